@@ -1,67 +1,116 @@
+#[path = "codegen/bindings.rs"]
+mod codegen;
+
 use anyhow::{format_err, Result};
+use object::read::{archive::ArchiveFile, coff::ImportFile};
+use object::{BinaryFormat, Object, ObjectSymbol};
+use std::collections::HashSet;
 use std::fs;
+use std::io::{self, Write};
 use std::{
     env,
     path::{Path, PathBuf},
 };
 
-const DARKNET_SRC_ENV: &'static str = "DARKNET_SRC";
-const DARKNET_INCLUDE_PATH_ENV: &'static str = "DARKNET_INCLUDE_PATH";
-const CUDA_PATH_ENV: &'static str = "CUDA_PATH";
-const CUDA_ARCHITECTURES_ENV: &'static str = "CUDA_ARCHITECTURES";
+const DARKNET_SRC_ENV: &str = "DARKNET_SRC";
+const DARKNET_INCLUDE_PATH_ENV: &str = "DARKNET_INCLUDE_PATH";
+const CUDA_PATH_ENV: &str = "CUDA_PATH";
+const CUDA_ARCHITECTURES_ENV: &str = "CUDA_ARCHITECTURES";
+const DARKNET_LIB_DIR_ENV: &str = "DARKNET_LIB_DIR";
+const DARKNET_SYSROOT_ENV: &str = "DARKNET_SYSROOT";
+const DARKNET_CMAKE_ARGS_ENV: &str = "DARKNET_CMAKE_ARGS";
+const DARKNET_SRC_ARCHIVE_ENV: &str = "DARKNET_SRC_ARCHIVE";
+const DARKNET_SRC_ARCHIVE_SHA256_OVERRIDE_ENV: &str = "DARKNET_SRC_ARCHIVE_SHA256_OVERRIDE";
+const DARKNET_BUILD_VERBOSE_ENV: &str = "DARKNET_BUILD_VERBOSE";
+const DARKNET_LIB_OPTIONAL_ENV: &str = "DARKNET_LIB_OPTIONAL";
+
+// Version of the darknet headers the bindings in `src/bindings/` were generated
+// from. A libdarknet found through pkg-config must report the same major and
+// minor version.
+const BUNDLED_DARKNET_VERSION: &str = "0.2.5";
+// SHA-256 of GitHub's `.tar.gz` archive of the darknet revision the bundled
+// bindings were generated from. `DARKNET_SRC_ARCHIVE` must match it unless
+// `DARKNET_SRC_ARCHIVE_SHA256_OVERRIDE` names another checksum. No revision is
+// pinned yet, so for now every archive needs the override.
+const DARKNET_SRC_ARCHIVE_SHA256: Option<&str> = None;
+// darknet's Makefile builds `libdarknet`, its CMake build installs `libdark`.
+const RUNTIME_LIBRARY_NAMES: &[&str] = &["darknet", "dark"];
+const RUNTIME_LIBRARY_PREFIXES: &[&str] = &[
+    "/usr/local/lib",
+    "/usr/local/lib64",
+    "/opt/darknet/lib",
+    "/opt/darknet",
+    "/usr/lib",
+    "/usr/lib64",
+];
 
 lazy_static::lazy_static! {
-    static ref BINDINGS_SRC_PATH: PathBuf = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("Failed to get CARGO_MANIFEST_DIR")).join("src").join("bindings.rs");
     static ref BINDINGS_TARGET_PATH: PathBuf = PathBuf::from(env::var("OUT_DIR").expect("Failed to get OUT_DIR")).join("bindings.rs");
+    static ref BUILD_INFO_TARGET_PATH: PathBuf = PathBuf::from(env::var("OUT_DIR").expect("Failed to get OUT_DIR")).join("build_info.rs");
+    static ref ARCHIVE_SOURCE_PATH: PathBuf = PathBuf::from(env::var("OUT_DIR").expect("Failed to get OUT_DIR")).join("darknet-src");
     static ref LIBRARY_PATH: PathBuf = PathBuf::from(env::var("OUT_DIR").expect("Failed to get OUT_DIR")).join("darknet");
 }
 
-// Recursively copy directory
-// Ref: https://stackoverflow.com/a/60406693
-// Modified to remove target files right before copying to circumvent permission problems.
-fn copy<U: AsRef<Path>, V: AsRef<Path>>(from: U, to: V) -> Result<(), std::io::Error> {
-    let mut stack = Vec::new();
-    stack.push(PathBuf::from(from.as_ref()));
-    let output_root = PathBuf::from(to.as_ref());
-    let input_root = PathBuf::from(from.as_ref()).components().count();
-    while let Some(working_path) = stack.pop() {
-        println!("process: {:?}", &working_path);
-        // Generate a relative path
-        let src: PathBuf = working_path.components().skip(input_root).collect();
-        // Create a destination if missing
-        let dest = if src.components().count() == 0 {
-            output_root.clone()
-        } else {
-            output_root.join(&src)
-        };
-        if fs::metadata(&dest).is_err() {
-            println!(" mkdir: {:?}", dest);
-            fs::create_dir_all(&dest)?;
-        }
-        for entry in fs::read_dir(working_path)? {
+// Copies the directory tree `from` into `to`, skipping `.git`. Files whose size
+// and contents are unchanged are left alone, so CMake does not rebuild them.
+// Files in `to` that are not in `from` are kept, since darknet's CMakeLists
+// writes generated files into its source directory. Returns the number of files
+// copied.
+fn sync_dir(from: &Path, to: &Path, verbose: bool) -> io::Result<usize> {
+    let mut copied = 0;
+    let mut stack = vec![PathBuf::new()];
+    while let Some(relative) = stack.pop() {
+        let src_dir = from.join(&relative);
+        let dest_dir = to.join(&relative);
+        fs::create_dir_all(&dest_dir)?;
+        for entry in fs::read_dir(&src_dir)? {
             let entry = entry?;
-            let path = entry.path();
-            if path.is_dir() {
-                stack.push(path);
-            } else {
-                match path.file_name() {
-                    Some(filename) => {
-                        let dest_path = dest.join(filename);
-                        println!("  copy: {:?} -> {:?}", &path, &dest_path);
-                        // Some `git` files are created with write protection, so replacing them
-                        // directly can fail with a permissions error. Remove the destination file
-                        // first. Ignore any errors, only the fs::copy() call is critical.
-                        fs::remove_file(&dest_path).ok();
-                        fs::copy(&path, &dest_path)?;
-                    }
-                    None => {
-                        println!("failed: {:?}", path);
-                    }
-                }
+            let name = entry.file_name();
+            if name == ".git" {
+                continue;
+            }
+            let src = entry.path();
+            if src.is_dir() {
+                stack.push(relative.join(&name));
+                continue;
+            }
+            let dest = dest_dir.join(&name);
+            if is_up_to_date(&src, &dest)? {
+                continue;
+            }
+            if verbose {
+                println!("copy: {} -> {}", src.display(), dest.display());
             }
+            // Some `git` files are created with write protection, so replacing them
+            // directly can fail with a permissions error. Remove the destination file
+            // first. Ignore any errors, only the fs::copy() call is critical.
+            fs::remove_file(&dest).ok();
+            fs::copy(&src, &dest)?;
+            copied += 1;
         }
     }
-    Ok(())
+    Ok(copied)
+}
+
+// Whether `dest` already has the contents of `src`. Contents are only compared
+// when `src` was modified after `dest` was written, e.g. by a fresh checkout.
+fn is_up_to_date(src: &Path, dest: &Path) -> io::Result<bool> {
+    let dest_metadata = match fs::metadata(dest) {
+        Ok(metadata) if metadata.is_file() => metadata,
+        _ => return Ok(false),
+    };
+    let src_metadata = fs::metadata(src)?;
+    if src_metadata.len() != dest_metadata.len() {
+        return Ok(false);
+    }
+    if let (Ok(src_modified), Ok(dest_modified)) =
+        (src_metadata.modified(), dest_metadata.modified())
+    {
+        if src_modified <= dest_modified {
+            return Ok(true);
+        }
+    }
+    Ok(fs::read(src)? == fs::read(dest)?)
 }
 
 // Guess the cmake profile using the rule defined in the link.
@@ -130,22 +179,58 @@ fn gen_bindings<P>(include_path: P) -> Result<()>
 where
     P: AsRef<Path>,
 {
-    bindgen::Builder::default()
-        .header(
-            include_path
-                .as_ref()
-                .join("darknet.h")
-                .to_str()
-                .ok_or_else(|| format_err!("cannot create path to darknet.h"))?,
-        )
+    let mut builder = codegen::builder(
+        &include_path.as_ref().join("darknet.h"),
+        is_dlopen_enabled(),
+    );
+    // bindgen passes `--target` from `TARGET` to clang itself.
+    if let Some(sysroot) = sysroot() {
+        builder = builder.clang_arg(format!("--sysroot={}", sysroot.display()));
+    }
+    let bindings = builder
         .generate()
-        .map_err(|_| format_err!("failed to generate bindings"))?
-        .write_to_file(&*BINDINGS_TARGET_PATH)?;
+        .map_err(|_| format_err!("failed to generate bindings"))?;
+    bindings.write_to_file(&*BINDINGS_TARGET_PATH)?;
+    if is_dlopen_enabled() {
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .open(&*BINDINGS_TARGET_PATH)?;
+        file.write_all(codegen::symbol_list(&bindings.to_string()).as_bytes())?;
+    }
     Ok(())
 }
 
+// The checked-in bindings for the target. Other targets need `buildtime-bindgen`,
+// as bindings for a different target may not match its layouts.
+fn pregenerated_bindings() -> Result<PathBuf> {
+    let stem = codegen::file_stem(&target_arch(), &target_os(), &target_env());
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bindings");
+    if !codegen::PREGENERATED_TARGETS
+        .iter()
+        .any(|&(_, known)| known == stem)
+    {
+        let known: Vec<_> = codegen::PREGENERATED_TARGETS
+            .iter()
+            .map(|&(triple, _)| triple)
+            .collect();
+        return Err(format_err!(
+            "no pre-generated darknet bindings for {}, only for {}. Enable the \
+             `buildtime-bindgen` feature to generate them from the darknet headers",
+            env::var("TARGET").unwrap_or(stem),
+            known.join(", ")
+        ));
+    }
+    Ok(dir.join(codegen::file_name(&stem, is_dlopen_enabled())))
+}
+
 fn is_dynamic() -> bool {
-    return cfg!(feature = "dylib");
+    cfg!(feature = "dylib")
+}
+
+fn is_dlopen_enabled() -> bool {
+    cfg!(feature = "dlopen")
 }
 
 fn is_cuda_enabled() -> bool {
@@ -164,18 +249,53 @@ fn is_openmp_enabled() -> bool {
     cfg!(feature = "enable-openmp")
 }
 
-fn build_with_cmake<P>(path: P) -> Result<()>
+// darknet only has SSE and AVX code paths for x86.
+fn is_avx_enabled() -> bool {
+    cfg!(feature = "enable-avx") && matches!(target_arch().as_str(), "x86" | "x86_64")
+}
+
+fn is_cudnn_half_enabled() -> bool {
+    cfg!(feature = "cudnn-half")
+}
+
+fn is_zed_camera_enabled() -> bool {
+    cfg!(feature = "enable-zed-camera")
+}
+
+fn build_with_cmake<P>(path: P) -> Result<BuildInfo>
 where
     P: AsRef<Path>,
 {
     let link = if is_dynamic() { "dylib" } else { "static" };
     let path = path.as_ref();
-    copy(path, LIBRARY_PATH.as_path())?;
+    let mut info = BuildInfo {
+        source: Some(path.to_owned()),
+        git_revision: git_revision(path),
+        cmake_profile: Some(guess_cmake_profile()),
+        linkage: if is_dynamic() { "Dynamic" } else { "Static" },
+        lib_dir: None,
+        features: Some(BuildFeatures::from_cargo_features()),
+    };
+    // CMake reruns when the source changes, and only the files that changed are
+    // copied so the rest are not rebuilt.
+    for dir in &["CMakeLists.txt", "cmake", "include", "src", "3rdparty"] {
+        println!("cargo:rerun-if-changed={}", path.join(dir).display());
+    }
+    let copied = sync_dir(
+        path,
+        LIBRARY_PATH.as_path(),
+        env::var_os(DARKNET_BUILD_VERBOSE_ENV).is_some(),
+    )?;
+    println!(
+        "copied {} changed files from {} to {}",
+        copied,
+        path.display(),
+        LIBRARY_PATH.display()
+    );
     let path = LIBRARY_PATH.as_path();
 
     let mut config = cmake::Config::new(path);
     config
-        .uses_cxx11()
         .define("BUILD_SHARED_LIBS", if is_dynamic() { "ON" } else { "OFF" })
         .define("ENABLE_CUDA", if is_cuda_enabled() { "ON" } else { "OFF" })
         .define(
@@ -186,6 +306,18 @@ where
             "ENABLE_OPENCV",
             if is_opencv_enabled() { "ON" } else { "OFF" },
         )
+        .define(
+            "ENABLE_CUDNN_HALF",
+            if is_cudnn_half_enabled() { "ON" } else { "OFF" },
+        )
+        .define(
+            "ENABLE_SSE_AND_AVX_FLAGS",
+            if is_avx_enabled() { "ON" } else { "OFF" },
+        )
+        .define(
+            "ENABLE_ZED_CAMERA",
+            if is_zed_camera_enabled() { "ON" } else { "OFF" },
+        )
         .define(
             "CUDA_ARCHITECTURES",
             env::var_os(CUDA_ARCHITECTURES_ENV).unwrap_or_else(|| "Auto".into()),
@@ -197,36 +329,58 @@ where
         // https://cmake.org/cmake/help/latest/variable/CMAKE_DISABLE_FIND_PACKAGE_PackageName.html
         config.define("CMAKE_DISABLE_FIND_PACKAGE_OpenMP", "ON");
     }
+    configure_cross_compilation(&mut config);
+    configure_extra_args(&mut config);
     let dst = config.build();
 
     // link to darknet
-    println!("cargo:rustc-link-search={}", dst.join("build").display());
-    match guess_cmake_profile() {
-        "Debug" => println!("cargo:rustc-link-lib={}=darknetd", link),
-        _ => println!("cargo:rustc-link-lib={}=darknet", link),
-    }
+    let lib_dir = dst.join("build");
+    let lib_name = match guess_cmake_profile() {
+        "Debug" => "darknetd",
+        _ => "darknet",
+    };
+    println!("cargo:rustc-link-search={}", lib_dir.display());
+    println!("cargo:rustc-link-lib={}={}", link, lib_name);
+    info.lib_dir = Some(lib_dir.clone());
+    emit_link_metadata(Some(&path.join("include")), &lib_dir, lib_name, link);
+    println!("cargo:root={}", dst.display());
+    println!("cargo:cuda={}", is_cuda_enabled());
+    println!("cargo:cudnn={}", is_cudnn_enabled());
+    println!("cargo:opencv={}", is_opencv_enabled());
+    println!("cargo:openmp={}", is_openmp_enabled());
 
     // link dependent libraries if linking to static library
     if !is_dynamic() {
-        if cfg!(target_os = "macos") {
-            if is_openmp_enabled() {
-                println!("cargo:rustc-link-lib=omp");
-            }
-            println!("cargo:rustc-link-lib=c++");
-        } else {
-            if is_openmp_enabled() {
-                println!("cargo:rustc-link-lib=gomp");
-            }
-            println!("cargo:rustc-link-lib=stdc++");
+        // The build script runs on the host, so decide by the target, not `cfg!`.
+        let (openmp, cxx) = match (target_os().as_str(), target_env().as_str()) {
+            // MSVC links its OpenMP and C++ runtimes by itself.
+            ("windows", "msvc") => (None, None),
+            ("macos", _) | ("ios", _) | ("freebsd", _) => (Some("omp"), Some("c++")),
+            ("android", _) => (Some("omp"), Some("c++_shared")),
+            _ => (Some("gomp"), Some("stdc++")),
+        };
+        if let (true, Some(openmp)) = (is_openmp_enabled(), openmp) {
+            println!("cargo:rustc-link-lib={}", openmp);
+        }
+        if let Some(cxx) = cxx {
+            println!("cargo:rustc-link-lib={}", cxx);
         }
         if is_cuda_enabled() {
             println!("cargo:rustc-link-lib=cudart");
             println!("cargo:rustc-link-lib=cublas");
             println!("cargo:rustc-link-lib=curand");
-            let cuda_lib_path = env::var_os(CUDA_PATH_ENV)
+            let cuda_path = env::var_os(CUDA_PATH_ENV)
                 .map(PathBuf::from)
-                .unwrap_or_else(|| "/opt/cuda".into())
-                .join("lib64");
+                .unwrap_or_else(|| "/opt/cuda".into());
+            // Cross-compilation packages of CUDA keep target libraries apart.
+            let cuda_lib_path = if is_cross_compiling() {
+                cuda_path
+                    .join("targets")
+                    .join(format!("{}-{}", target_arch(), target_os()))
+                    .join("lib")
+            } else {
+                cuda_path.join("lib64")
+            };
             println!("cargo:rustc-link-search={}", cuda_lib_path.display());
         }
         if is_cudnn_enabled() {
@@ -236,13 +390,356 @@ where
 
     gen_bindings(path.join("include"))?;
 
-    Ok(())
+    Ok(info)
+}
+
+// Finds the libdarknet to link for the `runtime` feature: in `DARKNET_LIB_DIR` if
+// set, otherwise through pkg-config, otherwise in common prefixes. Returns the
+// directory of the library.
+fn link_runtime_library() -> Result<PathBuf> {
+    let symbols = required_symbols(&fs::read_to_string(&*BINDINGS_TARGET_PATH)?);
+    if let Some(dir) = env::var_os(DARKNET_LIB_DIR_ENV) {
+        let dir = PathBuf::from(dir);
+        return match find_runtime_library(&dir, &symbols) {
+            Ok((name, _)) => {
+                link_runtime_library_in(&dir, name);
+                Ok(dir)
+            }
+            Err(reason) => Err(format_err!(
+                "{}={}: {}",
+                DARKNET_LIB_DIR_ENV,
+                dir.display(),
+                reason
+            )),
+        };
+    }
+
+    let mut tried = Vec::new();
+    match pkg_config::Config::new()
+        .cargo_metadata(false)
+        .env_metadata(true)
+        .probe("darknet")
+    {
+        Ok(library) => {
+            let mismatch = check_pkg_config_library(&library, &symbols);
+            match mismatch {
+                None => {
+                    for dir in &library.link_paths {
+                        println!("cargo:rustc-link-search=native={}", dir.display());
+                    }
+                    for lib in &library.libs {
+                        println!("cargo:rustc-link-lib={}", lib);
+                    }
+                    if let (Some(lib_dir), Some(lib)) =
+                        (library.link_paths.first(), library.libs.first())
+                    {
+                        let include = library
+                            .include_paths
+                            .first()
+                            .cloned()
+                            .or_else(runtime_include_path);
+                        emit_link_metadata(include.as_deref(), lib_dir, lib, "dylib");
+                    }
+                    return Ok(library.link_paths.first().cloned().unwrap_or_default());
+                }
+                Some(reason) => tried.push(format!("pkg-config: {}", reason)),
+            }
+        }
+        Err(_) => tried.push("pkg-config: no `darknet` package".to_owned()),
+    }
+
+    let mut prefixes: Vec<PathBuf> = Vec::new();
+    // Host directories hold host libraries when cross-compiling, unless they are
+    // under a sysroot. Debian's multiarch directory is named after the target.
+    if !is_cross_compiling() || sysroot().is_some() {
+        prefixes.extend(RUNTIME_LIBRARY_PREFIXES.iter().map(PathBuf::from));
+    }
+    let multiarch = match target_arch().as_str() {
+        "arm" => "arm-linux-gnueabihf".to_owned(),
+        arch => format!("{}-linux-gnu", arch),
+    };
+    prefixes.push(Path::new("/usr/lib").join(multiarch));
+    if let Some(sysroot) = sysroot() {
+        prefixes = prefixes
+            .into_iter()
+            .map(|dir| sysroot.join(dir.strip_prefix("/").unwrap_or(&dir)))
+            .collect();
+    }
+    for dir in prefixes {
+        match find_runtime_library(&dir, &symbols) {
+            Ok((name, _)) => {
+                link_runtime_library_in(&dir, name);
+                return Ok(dir);
+            }
+            Err(reason) => tried.push(format!("{}: {}", dir.display(), reason)),
+        }
+    }
+
+    Err(format_err!(
+        "cannot find libdarknet {} for the `runtime` feature. Looked in:\n  {}\n\
+         Set {} to the directory containing the library, or make it known to pkg-config \
+         as `darknet`. Set {} to build without linking it.",
+        BUNDLED_DARKNET_VERSION,
+        tried.join("\n  "),
+        DARKNET_LIB_DIR_ENV,
+        DARKNET_LIB_OPTIONAL_ENV
+    ))
+}
+
+fn link_runtime_library_in(dir: &Path, name: &str) {
+    println!("cargo:rustc-link-search=native={}", dir.display());
+    println!("cargo:rustc-link-lib=dylib={}", name);
+    emit_link_metadata(runtime_include_path().as_deref(), dir, name, "dylib");
+}
+
+// The headers the runtime bindings correspond to, if available.
+fn runtime_include_path() -> Option<PathBuf> {
+    env::var_os(DARKNET_INCLUDE_PATH_ENV)
+        .map(PathBuf::from)
+        .or_else(|| {
+            Some(
+                PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                    .join("darknet")
+                    .join("include"),
+            )
+        })
+        .filter(|path| path.join("darknet.h").is_file())
+}
+
+// Metadata for crates depending on darknet-sys, available to their build scripts
+// as `DEP_DARKNET_INCLUDE`, `DEP_DARKNET_LIB_DIR` and so on through `links`.
+fn emit_link_metadata(include: Option<&Path>, lib_dir: &Path, lib: &str, link: &str) {
+    if let Some(include) = include {
+        println!("cargo:include={}", include.display());
+    }
+    println!("cargo:lib_dir={}", lib_dir.display());
+    println!("cargo:lib={}", lib);
+    println!("cargo:link={}", link);
+}
+
+// File names of the shared library `name` for the target, e.g. `libdarknet.so`.
+fn runtime_library_file_names(name: &str) -> Vec<String> {
+    match env::var("CARGO_CFG_TARGET_OS").as_deref() {
+        Ok("windows") => vec![format!("{}.lib", name), format!("lib{}.dll.a", name)],
+        Ok("macos") | Ok("ios") => vec![format!("lib{}.dylib", name)],
+        _ => vec![format!("lib{}.so", name)],
+    }
+}
+
+// Returns the library name and path of the first matching libdarknet in `dir`, or
+// why there is none.
+fn find_runtime_library(
+    dir: &Path,
+    symbols: &[String],
+) -> std::result::Result<(&'static str, PathBuf), String> {
+    let mut reasons = Vec::new();
+    for name in RUNTIME_LIBRARY_NAMES {
+        for file_name in runtime_library_file_names(name) {
+            let path = dir.join(file_name);
+            if !path.is_file() {
+                continue;
+            }
+            match check_symbols(&path, symbols) {
+                Ok(()) => return Ok((name, path)),
+                Err(reason) => reasons.push(reason),
+            }
+        }
+    }
+    if reasons.is_empty() {
+        let names: Vec<_> = RUNTIME_LIBRARY_NAMES
+            .iter()
+            .flat_map(|name| runtime_library_file_names(name))
+            .collect();
+        Err(format!("no {}", names.join(" or ")))
+    } else {
+        Err(reasons.join("; "))
+    }
+}
+
+fn check_pkg_config_library(library: &pkg_config::Library, symbols: &[String]) -> Option<String> {
+    let wanted: Vec<_> = BUNDLED_DARKNET_VERSION.split('.').take(2).collect();
+    let found: Vec<_> = library.version.split('.').take(2).collect();
+    if !library.version.is_empty() && wanted != found {
+        return Some(format!(
+            "version {} does not match the bundled headers, {}",
+            library.version, BUNDLED_DARKNET_VERSION
+        ));
+    }
+    // Check the symbols too if the library file can be located.
+    let path = library.link_paths.iter().find_map(|dir| {
+        library.libs.iter().find_map(|name| {
+            runtime_library_file_names(name)
+                .into_iter()
+                .map(|file_name| dir.join(file_name))
+                .find(|path| path.is_file())
+        })
+    })?;
+    check_symbols(&path, symbols).err()
+}
+
+// Names of the functions and statics the bindings import, without the GPU-only
+// functions unless `enable-cuda` asks for a GPU build.
+fn required_symbols(bindings: &str) -> Vec<String> {
+    bindings
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_start();
+            line.strip_prefix("pub fn ")
+                .or_else(|| line.strip_prefix("pub static mut "))
+                .map(|rest| {
+                    rest.chars()
+                        .take_while(|c| c.is_alphanumeric() || *c == '_')
+                        .collect::<String>()
+                })
+        })
+        .filter(|name| is_cuda_enabled() || !codegen::GPU_ONLY_FUNCTIONS.contains(&name.as_str()))
+        .collect()
+}
+
+// Checks that the library's symbol table has every symbol of the bindings, which
+// fails for libraries built from other darknet versions.
+fn check_symbols(path: &Path, symbols: &[String]) -> std::result::Result<(), String> {
+    let contents =
+        fs::read(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    let names = exported_symbols(&contents)
+        .map_err(|err| format!("cannot read symbols of {}: {}", path.display(), err))?;
+    let missing: Vec<_> = symbols
+        .iter()
+        .filter(|symbol| !names.contains(symbol.as_bytes()))
+        .map(String::as_str)
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} does not match the bundled darknet.h {}, missing {}",
+            path.display(),
+            BUNDLED_DARKNET_VERSION,
+            missing.join(", ")
+        ))
+    }
+}
+
+// The symbols a shared library exports, from its dynamic symbol table, or the
+// symbols a Windows import library defines.
+fn exported_symbols(data: &[u8]) -> object::Result<HashSet<Vec<u8>>> {
+    let mut names = HashSet::new();
+    if let Ok(archive) = ArchiveFile::parse(data) {
+        // MSVC import libraries hold short import entries, MinGW ones hold objects.
+        for member in archive.members() {
+            let member = member?.data(data)?;
+            if let Ok(import) = ImportFile::parse(member) {
+                names.insert(import.symbol().to_vec());
+            } else if let Ok(file) = object::File::parse(member) {
+                names.extend(
+                    file.symbols()
+                        .filter(|symbol| symbol.is_global() && symbol.is_definition())
+                        .filter_map(|symbol| symbol.name_bytes().ok())
+                        .map(<[u8]>::to_vec),
+                );
+            }
+        }
+        return Ok(names);
+    }
+    let file = object::File::parse(data)?;
+    // Mach-O prefixes C symbols with `_`.
+    let prefix: &[u8] = if file.format() == BinaryFormat::MachO {
+        b"_"
+    } else {
+        b""
+    };
+    for export in file.exports()? {
+        if let Some(name) = export.name().strip_prefix(prefix) {
+            names.insert(name.to_vec());
+        }
+    }
+    Ok(names)
+}
+
+fn target_os() -> String {
+    env::var("CARGO_CFG_TARGET_OS").unwrap_or_default()
 }
 
-fn build_runtime() -> Result<()> {
+fn target_env() -> String {
+    env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default()
+}
+
+fn target_arch() -> String {
+    env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default()
+}
+
+fn is_cross_compiling() -> bool {
+    env::var("TARGET").ok() != env::var("HOST").ok()
+}
+
+fn sysroot() -> Option<PathBuf> {
+    env::var_os(DARKNET_SYSROOT_ENV).map(PathBuf::from)
+}
+
+// Describes the target to CMake when cross-compiling. The cmake crate already
+// passes the target's compilers, which `CC_<target>` and `CXX_<target>` override,
+// and a toolchain file from `CMAKE_TOOLCHAIN_FILE_<target>` replaces all of this.
+fn configure_cross_compilation(config: &mut cmake::Config) {
+    let target = env::var("TARGET").unwrap_or_default();
+    let has_toolchain_file = [
+        format!("CMAKE_TOOLCHAIN_FILE_{}", target),
+        format!("CMAKE_TOOLCHAIN_FILE_{}", target.replace('-', "_")),
+        "TARGET_CMAKE_TOOLCHAIN_FILE".to_owned(),
+        "CMAKE_TOOLCHAIN_FILE".to_owned(),
+    ]
+    .iter()
+    .any(|var| env::var_os(var).is_some());
+    if !is_cross_compiling() || has_toolchain_file {
+        return;
+    }
+    let system_name = match target_os().as_str() {
+        "linux" => "Linux",
+        "android" => "Android",
+        "macos" => "Darwin",
+        "windows" => "Windows",
+        "freebsd" => "FreeBSD",
+        _ => "Generic",
+    };
+    config.define("CMAKE_SYSTEM_NAME", system_name);
+    // The first component of the triple, e.g. `armv7` rather than `arm`, which
+    // darknet's CMakeLists checks to choose SIMD flags.
+    config.define(
+        "CMAKE_SYSTEM_PROCESSOR",
+        target.split('-').next().unwrap_or_default(),
+    );
+    if let Some(sysroot) = sysroot() {
+        config
+            .define("CMAKE_SYSROOT", &sysroot)
+            .define("CMAKE_FIND_ROOT_PATH", &sysroot)
+            .define("CMAKE_FIND_ROOT_PATH_MODE_PROGRAM", "NEVER")
+            .define("CMAKE_FIND_ROOT_PATH_MODE_LIBRARY", "ONLY")
+            .define("CMAKE_FIND_ROOT_PATH_MODE_INCLUDE", "ONLY")
+            .define("CMAKE_FIND_ROOT_PATH_MODE_PACKAGE", "ONLY");
+    }
+}
+
+// Passes the whitespace-separated arguments in `DARKNET_CMAKE_ARGS` to CMake.
+// `-DNAME=VALUE` arguments come after the defines above and override them.
+fn configure_extra_args(config: &mut cmake::Config) {
+    let args = match env::var(DARKNET_CMAKE_ARGS_ENV) {
+        Ok(args) => args,
+        Err(_) => return,
+    };
+    for arg in args.split_whitespace() {
+        match arg
+            .strip_prefix("-D")
+            .and_then(|define| define.split_once('='))
+        {
+            Some((name, value)) => config.define(name, value),
+            None => config.configure_arg(arg),
+        };
+    }
+}
+
+fn build_runtime() -> Result<BuildInfo> {
     if cfg!(feature = "buildtime-bindgen") {
         let include_path = env::var_os(DARKNET_INCLUDE_PATH_ENV)
-            .map(|value| PathBuf::from(value))
+            .map(PathBuf::from)
             .unwrap_or_else(|| {
                 PathBuf::from(env!("CARGO_MANIFEST_DIR"))
                     .join("darknet")
@@ -250,21 +747,244 @@ fn build_runtime() -> Result<()> {
             });
         gen_bindings(include_path)?;
     } else {
-        fs::copy(&*BINDINGS_SRC_PATH, &*BINDINGS_TARGET_PATH)
-            .expect("Failed to copy bindings.rs to OUT_DIR");
+        fs::copy(pregenerated_bindings()?, &*BINDINGS_TARGET_PATH)?;
+    }
+    if is_dlopen_enabled() {
+        return Ok(BuildInfo::new("Dlopen"));
+    }
+    let lib_dir = match link_runtime_library() {
+        Ok(lib_dir) => Some(lib_dir),
+        // Without a library, leave linking to the dependent crate as before the
+        // lookup existed, e.g. to run clippy on a machine without darknet.
+        Err(err) if env::var_os(DARKNET_LIB_OPTIONAL_ENV).is_some() => {
+            for line in err.to_string().lines() {
+                println!("cargo:warning={}", line);
+            }
+            None
+        }
+        Err(err) => return Err(err),
+    };
+    Ok(BuildInfo {
+        lib_dir,
+        ..BuildInfo::new("Dynamic")
+    })
+}
+
+// Builds from `DARKNET_SRC` if set, otherwise from the archive in
+// `DARKNET_SRC_ARCHIVE`, otherwise from the `darknet` submodule.
+fn build_from_source() -> Result<BuildInfo> {
+    if let Some(src) = env::var_os(DARKNET_SRC_ENV) {
+        let src_dir = PathBuf::from(src);
+        check_source_dir(&src_dir, DARKNET_SRC_ENV)?;
+        return build_with_cmake(src_dir);
     }
+    if let Some(archive) = env::var_os(DARKNET_SRC_ARCHIVE_ENV) {
+        let archive = PathBuf::from(archive);
+        println!("cargo:rerun-if-changed={}", archive.display());
+        let src_dir = unpack_source_archive(&archive)?;
+        let mut info = build_with_cmake(src_dir)?;
+        info.source = Some(archive);
+        return Ok(info);
+    }
+    let src_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("darknet");
+    if !src_dir.join("CMakeLists.txt").is_file() {
+        return Err(format_err!(
+            "the darknet source in {} is missing, e.g. because the git submodule was not \
+             checked out. Run `git submodule update --init`, point {} at a darknet \
+             checkout, point {} at a darknet source archive, or link to a prebuilt \
+             libdarknet with the `runtime` feature",
+            src_dir.display(),
+            DARKNET_SRC_ENV,
+            DARKNET_SRC_ARCHIVE_ENV
+        ));
+    }
+    build_with_cmake(src_dir)
+}
 
+fn check_source_dir(dir: &Path, origin: &str) -> Result<()> {
+    for file in &["CMakeLists.txt", "include/darknet.h"] {
+        if !dir.join(file).is_file() {
+            return Err(format_err!(
+                "{} from {} is not a darknet source tree: {} is missing",
+                dir.display(),
+                origin,
+                file
+            ));
+        }
+    }
     Ok(())
 }
 
-fn build_from_source() -> Result<()> {
-    let src_dir: PathBuf = match env::var_os(DARKNET_SRC_ENV) {
-        Some(src) => src.into(),
-        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("darknet"),
+// Unpacks a `.tar.gz`, `.tgz` or `.tar` archive of the darknet source into
+// `OUT_DIR` after checking its checksum against the pinned one or the override,
+// and returns
+// the source directory. An archive already unpacked with the same checksum is
+// reused.
+fn unpack_source_archive(archive: &Path) -> Result<PathBuf> {
+    use sha2::{Digest, Sha256};
+
+    let (expected, origin) = match env::var(DARKNET_SRC_ARCHIVE_SHA256_OVERRIDE_ENV) {
+        Ok(sha256) => (
+            sha256.trim().to_ascii_lowercase(),
+            DARKNET_SRC_ARCHIVE_SHA256_OVERRIDE_ENV,
+        ),
+        Err(_) => match DARKNET_SRC_ARCHIVE_SHA256 {
+            Some(sha256) => (sha256.to_owned(), "darknet-sys"),
+            None => {
+                return Err(format_err!(
+                    "darknet-sys {} pins no darknet source archive to check {} against. Set \
+                     {} to the SHA-256 of an archive you trust to build from it anyway",
+                    env!("CARGO_PKG_VERSION"),
+                    DARKNET_SRC_ARCHIVE_ENV,
+                    DARKNET_SRC_ARCHIVE_SHA256_OVERRIDE_ENV
+                ))
+            }
+        },
     };
-    build_with_cmake(src_dir)?;
+    let mut file = fs::File::open(archive)
+        .map_err(|err| format_err!("cannot open {}: {}", archive.display(), err))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    let actual = format!("{:x}", hasher.finalize());
+    if actual != expected {
+        return Err(format_err!(
+            "{} has SHA-256 {}, but {} pins {}",
+            archive.display(),
+            actual,
+            origin,
+            expected
+        ));
+    }
 
-    Ok(())
+    let root = ARCHIVE_SOURCE_PATH.as_path();
+    let stamp = root.join(".sha256");
+    if fs::read_to_string(&stamp).ok().as_deref() != Some(actual.as_str()) {
+        if root.exists() {
+            fs::remove_dir_all(root)?;
+        }
+        fs::create_dir_all(root)?;
+        let file = fs::File::open(archive)?;
+        let name = archive.to_string_lossy();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            tar::Archive::new(flate2::read::GzDecoder::new(file)).unpack(root)?;
+        } else if name.ends_with(".tar") {
+            tar::Archive::new(file).unpack(root)?;
+        } else {
+            return Err(format_err!(
+                "{} is not a .tar.gz, .tgz or .tar archive",
+                archive.display()
+            ));
+        }
+        fs::write(&stamp, &actual)?;
+    }
+
+    // Archives from GitHub put the tree in a single top-level directory.
+    let src_dir = if root.join("CMakeLists.txt").is_file() {
+        root.to_owned()
+    } else {
+        let dirs = fs::read_dir(root)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_dir())
+            .collect::<Vec<_>>();
+        match dirs.as_slice() {
+            [dir] => dir.clone(),
+            _ => root.to_owned(),
+        }
+    };
+    check_source_dir(&src_dir, DARKNET_SRC_ARCHIVE_ENV)?;
+    Ok(src_dir)
+}
+
+// How libdarknet was obtained, written to `OUT_DIR/build_info.rs` for the
+// `build_info` module.
+struct BuildInfo {
+    source: Option<PathBuf>,
+    git_revision: Option<String>,
+    cmake_profile: Option<&'static str>,
+    // Name of a `build_info::Linkage` variant.
+    linkage: &'static str,
+    lib_dir: Option<PathBuf>,
+    // Only known when building from source.
+    features: Option<BuildFeatures>,
+}
+
+struct BuildFeatures {
+    cuda: bool,
+    cudnn: bool,
+    opencv: bool,
+    openmp: bool,
+}
+
+impl BuildFeatures {
+    fn from_cargo_features() -> Self {
+        Self {
+            cuda: is_cuda_enabled(),
+            cudnn: is_cudnn_enabled(),
+            opencv: is_opencv_enabled(),
+            openmp: is_openmp_enabled(),
+        }
+    }
+}
+
+impl BuildInfo {
+    fn new(linkage: &'static str) -> Self {
+        Self {
+            source: None,
+            git_revision: None,
+            cmake_profile: None,
+            linkage,
+            lib_dir: None,
+            features: None,
+        }
+    }
+
+    fn write(&self) -> Result<()> {
+        fn option<T: std::fmt::Debug>(value: Option<T>) -> String {
+            match value {
+                Some(value) => format!("Some({:?})", value),
+                None => "None".to_owned(),
+            }
+        }
+        let path = |path: &Option<PathBuf>| option(path.as_ref().map(|p| p.to_string_lossy()));
+        let feature = |f: fn(&BuildFeatures) -> bool| option(self.features.as_ref().map(f));
+        let contents = format!(
+            "pub const SOURCE: Option<&str> = {};\n\
+             pub const GIT_REVISION: Option<&str> = {};\n\
+             pub const CMAKE_PROFILE: Option<&str> = {};\n\
+             pub const LINKAGE: Linkage = Linkage::{};\n\
+             pub const LIB_DIR: Option<&str> = {};\n\
+             pub const CUDA: Option<bool> = {};\n\
+             pub const CUDNN: Option<bool> = {};\n\
+             pub const OPENCV: Option<bool> = {};\n\
+             pub const OPENMP: Option<bool> = {};\n",
+            path(&self.source),
+            option(self.git_revision.as_ref()),
+            option(self.cmake_profile),
+            self.linkage,
+            path(&self.lib_dir),
+            feature(|f| f.cuda),
+            feature(|f| f.cudnn),
+            feature(|f| f.opencv),
+            feature(|f| f.openmp),
+        );
+        fs::write(&*BUILD_INFO_TARGET_PATH, contents)?;
+        Ok(())
+    }
+}
+
+// `git rev-parse HEAD` of the darknet source, if it is a git checkout.
+fn git_revision(path: &Path) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let revision = String::from_utf8(output.stdout).ok()?;
+    Some(revision.trim().to_owned()).filter(|revision| !revision.is_empty())
 }
 
 fn main() -> Result<()> {
@@ -272,18 +992,28 @@ fn main() -> Result<()> {
     println!("cargo:rerun-if-env-changed={}", DARKNET_INCLUDE_PATH_ENV);
     println!("cargo:rerun-if-env-changed={}", CUDA_PATH_ENV);
     println!("cargo:rerun-if-env-changed={}", CUDA_ARCHITECTURES_ENV);
+    println!("cargo:rerun-if-env-changed={}", DARKNET_LIB_DIR_ENV);
+    println!("cargo:rerun-if-env-changed={}", DARKNET_SYSROOT_ENV);
+    println!("cargo:rerun-if-env-changed={}", DARKNET_CMAKE_ARGS_ENV);
+    println!("cargo:rerun-if-env-changed={}", DARKNET_SRC_ARCHIVE_ENV);
+    println!("cargo:rerun-if-env-changed={}", DARKNET_BUILD_VERBOSE_ENV);
+    println!("cargo:rerun-if-env-changed={}", DARKNET_LIB_OPTIONAL_ENV);
+    println!(
+        "cargo:rerun-if-env-changed={}",
+        DARKNET_SRC_ARCHIVE_SHA256_OVERRIDE_ENV
+    );
     println!(
         "cargo:rerun-if-env-changed={}",
         BINDINGS_TARGET_PATH.display()
     );
     if cfg!(feature = "docs-rs") {
-        return Ok(());
+        return BuildInfo::new("Static").write();
     }
     // build from source by default
-    if cfg!(feature = "runtime") {
-        build_runtime()?;
+    let info = if cfg!(feature = "runtime") || is_dlopen_enabled() {
+        build_runtime()?
     } else {
-        build_from_source()?;
-    }
-    Ok(())
+        build_from_source()?
+    };
+    info.write()
 }
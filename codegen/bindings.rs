@@ -0,0 +1,79 @@
+// Bindgen configuration shared by build.rs, for `buildtime-bindgen`, and by the
+// xtask that regenerates the pre-generated bindings in `src/bindings/`.
+
+use std::path::Path;
+
+// darknet enums generated as Rust enums. Conversions to and from integers and
+// cfg spellings live in `src/enums.rs` and must be kept in sync with this list.
+const RUSTIFIED_ENUMS: &str =
+    "LAYER_TYPE|ACTIVATION|NMS_KIND|COST_TYPE|IOU_LOSS|IMTYPE|learning_rate_policy|data_type";
+
+// Functions darknet.h declares but only defines when built with `GPU`. CPU builds
+// only define `cuda_set_device`, as a no-op.
+pub const GPU_ONLY_FUNCTIONS: &[&str] = &[
+    "cuda_pull_array",
+    "cuda_pull_array_async",
+    "cuda_get_context",
+];
+
+// Targets with pre-generated bindings, as Rust target triple and file stem in
+// `src/bindings/`.
+pub const PREGENERATED_TARGETS: &[(&str, &str)] = &[
+    ("x86_64-unknown-linux-gnu", "x86_64-linux-gnu"),
+    ("aarch64-unknown-linux-gnu", "aarch64-linux-gnu"),
+    ("x86_64-unknown-linux-musl", "x86_64-linux-musl"),
+    ("aarch64-unknown-linux-musl", "aarch64-linux-musl"),
+];
+
+// File stem of the pre-generated bindings for a target, from the
+// `CARGO_CFG_TARGET_ARCH`, `_OS` and `_ENV` values.
+pub fn file_stem(arch: &str, os: &str, env: &str) -> String {
+    format!("{}-{}-{}", arch, os, env)
+}
+
+// File name of the pre-generated bindings with the given stem.
+pub fn file_name(stem: &str, dlopen: bool) -> String {
+    if dlopen {
+        format!("{}-dlopen.rs", stem)
+    } else {
+        format!("{}.rs", stem)
+    }
+}
+
+pub fn builder(header: &Path, dlopen: bool) -> bindgen::Builder {
+    let builder = bindgen::Builder::default()
+        .header(header.to_string_lossy())
+        // Only emit darknet's own items. Types from system headers that darknet's
+        // API refers to are blocklisted and taken from `libc` instead, so the
+        // output does not depend on the host's libc headers.
+        .allowlist_file(r".*[/\\]darknet\.h")
+        .blocklist_type("pthread_t")
+        .raw_line("pub use libc::pthread_t;")
+        .ctypes_prefix("::std::os::raw")
+        .rustified_non_exhaustive_enum(RUSTIFIED_ENUMS);
+    if dlopen {
+        // Load functions through a `Darknet` struct instead of linking to them.
+        // Every function is required, so the GPU-only ones are left out to load
+        // CPU builds too.
+        GPU_ONLY_FUNCTIONS
+            .iter()
+            .fold(builder, |builder, name| builder.blocklist_function(name))
+            .dynamic_library_name("Darknet")
+            .dynamic_link_require_all(true)
+    } else {
+        builder
+    }
+}
+
+// The names of all symbols loaded by `Darknet::from_library()`, appended to the
+// dlopen bindings so missing ones can be reported by name.
+pub fn symbol_list(bindings: &str) -> String {
+    let mut list = String::from("pub const DARKNET_SYMBOLS: &[&str] = &[\n");
+    for chunk in bindings.split("b\"").skip(1) {
+        if let Some(end) = chunk.find("\\0\"") {
+            list.push_str(&format!("    \"{}\",\n", &chunk[..end]));
+        }
+    }
+    list.push_str("];\n");
+    list
+}
@@ -0,0 +1,8647 @@
+/* automatically generated by rust-bindgen 0.65.1 */
+
+extern crate libloading;
+
+pub use libc::pthread_t;
+
+pub const SECRET_NUM: i32 = -1234;
+pub const UNUSED_ENUM_TYPE_UNUSED_DEF_VAL: UNUSED_ENUM_TYPE = 0;
+pub type UNUSED_ENUM_TYPE = ::std::os::raw::c_uint;
+extern "C" {
+    pub static mut gpu_index: ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct metadata {
+    pub classes: ::std::os::raw::c_int,
+    pub names: *mut *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_metadata() {
+    const UNINIT: ::std::mem::MaybeUninit<metadata> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<metadata>(),
+        16usize,
+        concat!("Size of: ", stringify!(metadata))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<metadata>(),
+        8usize,
+        concat!("Alignment of ", stringify!(metadata))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classes) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(metadata),
+            "::",
+            stringify!(classes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).names) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(metadata),
+            "::",
+            stringify!(names)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tree {
+    pub leaf: *mut ::std::os::raw::c_int,
+    pub n: ::std::os::raw::c_int,
+    pub parent: *mut ::std::os::raw::c_int,
+    pub child: *mut ::std::os::raw::c_int,
+    pub group: *mut ::std::os::raw::c_int,
+    pub name: *mut *mut ::std::os::raw::c_char,
+    pub groups: ::std::os::raw::c_int,
+    pub group_size: *mut ::std::os::raw::c_int,
+    pub group_offset: *mut ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_tree() {
+    const UNINIT: ::std::mem::MaybeUninit<tree> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<tree>(),
+        72usize,
+        concat!("Size of: ", stringify!(tree))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<tree>(),
+        8usize,
+        concat!("Alignment of ", stringify!(tree))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).leaf) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(leaf)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).n) as usize - ptr as usize },
+        8usize,
+        concat!("Offset of field: ", stringify!(tree), "::", stringify!(n))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).parent) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(parent)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).child) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(child)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).group) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(group)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).name) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(name)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).groups) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(groups)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).group_size) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(group_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).group_offset) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(tree),
+            "::",
+            stringify!(group_offset)
+        )
+    );
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum ACTIVATION {
+    LOGISTIC = 0,
+    RELU = 1,
+    RELU6 = 2,
+    RELIE = 3,
+    LINEAR = 4,
+    RAMP = 5,
+    TANH = 6,
+    PLSE = 7,
+    REVLEAKY = 8,
+    LEAKY = 9,
+    ELU = 10,
+    LOGGY = 11,
+    STAIR = 12,
+    HARDTAN = 13,
+    LHTAN = 14,
+    SELU = 15,
+    GELU = 16,
+    SWISH = 17,
+    MISH = 18,
+    HARD_MISH = 19,
+    NORM_CHAN = 20,
+    NORM_CHAN_SOFTMAX = 21,
+    NORM_CHAN_SOFTMAX_MAXVAL = 22,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum IOU_LOSS {
+    IOU = 0,
+    GIOU = 1,
+    MSE = 2,
+    DIOU = 3,
+    CIOU = 4,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NMS_KIND {
+    DEFAULT_NMS = 0,
+    GREEDY_NMS = 1,
+    DIOU_NMS = 2,
+    CORNERS_NMS = 3,
+}
+pub const YOLO_POINT_YOLO_CENTER: YOLO_POINT = 1;
+pub const YOLO_POINT_YOLO_LEFT_TOP: YOLO_POINT = 2;
+pub const YOLO_POINT_YOLO_RIGHT_BOTTOM: YOLO_POINT = 4;
+pub type YOLO_POINT = ::std::os::raw::c_uint;
+pub const WEIGHTS_TYPE_T_NO_WEIGHTS: WEIGHTS_TYPE_T = 0;
+pub const WEIGHTS_TYPE_T_PER_FEATURE: WEIGHTS_TYPE_T = 1;
+pub const WEIGHTS_TYPE_T_PER_CHANNEL: WEIGHTS_TYPE_T = 2;
+pub type WEIGHTS_TYPE_T = ::std::os::raw::c_uint;
+pub const WEIGHTS_NORMALIZATION_T_NO_NORMALIZATION: WEIGHTS_NORMALIZATION_T = 0;
+pub const WEIGHTS_NORMALIZATION_T_RELU_NORMALIZATION: WEIGHTS_NORMALIZATION_T = 1;
+pub const WEIGHTS_NORMALIZATION_T_SOFTMAX_NORMALIZATION: WEIGHTS_NORMALIZATION_T = 2;
+pub type WEIGHTS_NORMALIZATION_T = ::std::os::raw::c_uint;
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum IMTYPE {
+    PNG = 0,
+    BMP = 1,
+    TGA = 2,
+    JPG = 3,
+}
+pub const BINARY_ACTIVATION_MULT: BINARY_ACTIVATION = 0;
+pub const BINARY_ACTIVATION_ADD: BINARY_ACTIVATION = 1;
+pub const BINARY_ACTIVATION_SUB: BINARY_ACTIVATION = 2;
+pub const BINARY_ACTIVATION_DIV: BINARY_ACTIVATION = 3;
+pub type BINARY_ACTIVATION = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct contrastive_params {
+    pub sim: f32,
+    pub exp_sim: f32,
+    pub P: f32,
+    pub i: ::std::os::raw::c_int,
+    pub j: ::std::os::raw::c_int,
+    pub time_step_i: ::std::os::raw::c_int,
+    pub time_step_j: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_contrastive_params() {
+    const UNINIT: ::std::mem::MaybeUninit<contrastive_params> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<contrastive_params>(),
+        28usize,
+        concat!("Size of: ", stringify!(contrastive_params))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<contrastive_params>(),
+        4usize,
+        concat!("Alignment of ", stringify!(contrastive_params))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sim) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(contrastive_params),
+            "::",
+            stringify!(sim)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).exp_sim) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(contrastive_params),
+            "::",
+            stringify!(exp_sim)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).P) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(contrastive_params),
+            "::",
+            stringify!(P)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(contrastive_params),
+            "::",
+            stringify!(i)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).j) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(contrastive_params),
+            "::",
+            stringify!(j)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_step_i) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(contrastive_params),
+            "::",
+            stringify!(time_step_i)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_step_j) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(contrastive_params),
+            "::",
+            stringify!(time_step_j)
+        )
+    );
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum LAYER_TYPE {
+    CONVOLUTIONAL = 0,
+    DECONVOLUTIONAL = 1,
+    CONNECTED = 2,
+    MAXPOOL = 3,
+    LOCAL_AVGPOOL = 4,
+    SOFTMAX = 5,
+    DETECTION = 6,
+    DROPOUT = 7,
+    CROP = 8,
+    ROUTE = 9,
+    COST = 10,
+    NORMALIZATION = 11,
+    AVGPOOL = 12,
+    LOCAL = 13,
+    SHORTCUT = 14,
+    SCALE_CHANNELS = 15,
+    SAM = 16,
+    ACTIVE = 17,
+    RNN = 18,
+    GRU = 19,
+    LSTM = 20,
+    CONV_LSTM = 21,
+    HISTORY = 22,
+    CRNN = 23,
+    BATCHNORM = 24,
+    NETWORK = 25,
+    XNOR = 26,
+    REGION = 27,
+    YOLO = 28,
+    GAUSSIAN_YOLO = 29,
+    ISEG = 30,
+    REORG = 31,
+    REORG_OLD = 32,
+    UPSAMPLE = 33,
+    LOGXENT = 34,
+    L2NORM = 35,
+    EMPTY = 36,
+    BLANK = 37,
+    CONTRASTIVE = 38,
+    IMPLICIT = 39,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum COST_TYPE {
+    SSE = 0,
+    MASKED = 1,
+    L1 = 2,
+    SEG = 3,
+    SMOOTH = 4,
+    WGAN = 5,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct update_args {
+    pub batch: ::std::os::raw::c_int,
+    pub learning_rate: f32,
+    pub momentum: f32,
+    pub decay: f32,
+    pub adam: ::std::os::raw::c_int,
+    pub B1: f32,
+    pub B2: f32,
+    pub eps: f32,
+    pub t: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_update_args() {
+    const UNINIT: ::std::mem::MaybeUninit<update_args> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<update_args>(),
+        36usize,
+        concat!("Size of: ", stringify!(update_args))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<update_args>(),
+        4usize,
+        concat!("Alignment of ", stringify!(update_args))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).batch) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(batch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).learning_rate) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(learning_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).momentum) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(momentum)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).decay) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(decay)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).adam) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(adam)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).B1) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(B1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).B2) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(B2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).eps) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(eps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).t) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(update_args),
+            "::",
+            stringify!(t)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct layer {
+    pub type_: LAYER_TYPE,
+    pub activation: ACTIVATION,
+    pub lstm_activation: ACTIVATION,
+    pub cost_type: COST_TYPE,
+    pub forward: ::std::option::Option<unsafe extern "C" fn(arg1: layer, arg2: network_state)>,
+    pub backward: ::std::option::Option<unsafe extern "C" fn(arg1: layer, arg2: network_state)>,
+    pub update: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: layer,
+            arg2: ::std::os::raw::c_int,
+            arg3: f32,
+            arg4: f32,
+            arg5: f32,
+        ),
+    >,
+    pub forward_gpu: ::std::option::Option<unsafe extern "C" fn(arg1: layer, arg2: network_state)>,
+    pub backward_gpu: ::std::option::Option<unsafe extern "C" fn(arg1: layer, arg2: network_state)>,
+    pub update_gpu: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: layer,
+            arg2: ::std::os::raw::c_int,
+            arg3: f32,
+            arg4: f32,
+            arg5: f32,
+            arg6: f32,
+        ),
+    >,
+    pub share_layer: *mut layer,
+    pub train: ::std::os::raw::c_int,
+    pub avgpool: ::std::os::raw::c_int,
+    pub batch_normalize: ::std::os::raw::c_int,
+    pub shortcut: ::std::os::raw::c_int,
+    pub batch: ::std::os::raw::c_int,
+    pub dynamic_minibatch: ::std::os::raw::c_int,
+    pub forced: ::std::os::raw::c_int,
+    pub flipped: ::std::os::raw::c_int,
+    pub inputs: ::std::os::raw::c_int,
+    pub outputs: ::std::os::raw::c_int,
+    pub mean_alpha: f32,
+    pub nweights: ::std::os::raw::c_int,
+    pub nbiases: ::std::os::raw::c_int,
+    pub extra: ::std::os::raw::c_int,
+    pub truths: ::std::os::raw::c_int,
+    pub h: ::std::os::raw::c_int,
+    pub w: ::std::os::raw::c_int,
+    pub c: ::std::os::raw::c_int,
+    pub out_h: ::std::os::raw::c_int,
+    pub out_w: ::std::os::raw::c_int,
+    pub out_c: ::std::os::raw::c_int,
+    pub n: ::std::os::raw::c_int,
+    pub max_boxes: ::std::os::raw::c_int,
+    pub truth_size: ::std::os::raw::c_int,
+    pub groups: ::std::os::raw::c_int,
+    pub group_id: ::std::os::raw::c_int,
+    pub size: ::std::os::raw::c_int,
+    pub side: ::std::os::raw::c_int,
+    pub stride: ::std::os::raw::c_int,
+    pub stride_x: ::std::os::raw::c_int,
+    pub stride_y: ::std::os::raw::c_int,
+    pub dilation: ::std::os::raw::c_int,
+    pub antialiasing: ::std::os::raw::c_int,
+    pub maxpool_depth: ::std::os::raw::c_int,
+    pub maxpool_zero_nonmax: ::std::os::raw::c_int,
+    pub out_channels: ::std::os::raw::c_int,
+    pub reverse: f32,
+    pub coordconv: ::std::os::raw::c_int,
+    pub flatten: ::std::os::raw::c_int,
+    pub spatial: ::std::os::raw::c_int,
+    pub pad: ::std::os::raw::c_int,
+    pub sqrt: ::std::os::raw::c_int,
+    pub flip: ::std::os::raw::c_int,
+    pub index: ::std::os::raw::c_int,
+    pub scale_wh: ::std::os::raw::c_int,
+    pub binary: ::std::os::raw::c_int,
+    pub xnor: ::std::os::raw::c_int,
+    pub peephole: ::std::os::raw::c_int,
+    pub use_bin_output: ::std::os::raw::c_int,
+    pub keep_delta_gpu: ::std::os::raw::c_int,
+    pub optimized_memory: ::std::os::raw::c_int,
+    pub steps: ::std::os::raw::c_int,
+    pub history_size: ::std::os::raw::c_int,
+    pub bottleneck: ::std::os::raw::c_int,
+    pub time_normalizer: f32,
+    pub state_constrain: ::std::os::raw::c_int,
+    pub hidden: ::std::os::raw::c_int,
+    pub truth: ::std::os::raw::c_int,
+    pub smooth: f32,
+    pub dot: f32,
+    pub deform: ::std::os::raw::c_int,
+    pub grad_centr: ::std::os::raw::c_int,
+    pub sway: ::std::os::raw::c_int,
+    pub rotate: ::std::os::raw::c_int,
+    pub stretch: ::std::os::raw::c_int,
+    pub stretch_sway: ::std::os::raw::c_int,
+    pub angle: f32,
+    pub jitter: f32,
+    pub resize: f32,
+    pub saturation: f32,
+    pub exposure: f32,
+    pub shift: f32,
+    pub ratio: f32,
+    pub learning_rate_scale: f32,
+    pub clip: f32,
+    pub focal_loss: ::std::os::raw::c_int,
+    pub classes_multipliers: *mut f32,
+    pub label_smooth_eps: f32,
+    pub noloss: ::std::os::raw::c_int,
+    pub softmax: ::std::os::raw::c_int,
+    pub classes: ::std::os::raw::c_int,
+    pub detection: ::std::os::raw::c_int,
+    pub embedding_layer_id: ::std::os::raw::c_int,
+    pub embedding_output: *mut f32,
+    pub embedding_size: ::std::os::raw::c_int,
+    pub sim_thresh: f32,
+    pub track_history_size: ::std::os::raw::c_int,
+    pub dets_for_track: ::std::os::raw::c_int,
+    pub dets_for_show: ::std::os::raw::c_int,
+    pub track_ciou_norm: f32,
+    pub coords: ::std::os::raw::c_int,
+    pub background: ::std::os::raw::c_int,
+    pub rescore: ::std::os::raw::c_int,
+    pub objectness: ::std::os::raw::c_int,
+    pub does_cost: ::std::os::raw::c_int,
+    pub joint: ::std::os::raw::c_int,
+    pub noadjust: ::std::os::raw::c_int,
+    pub reorg: ::std::os::raw::c_int,
+    pub log: ::std::os::raw::c_int,
+    pub tanh: ::std::os::raw::c_int,
+    pub mask: *mut ::std::os::raw::c_int,
+    pub total: ::std::os::raw::c_int,
+    pub bflops: f32,
+    pub adam: ::std::os::raw::c_int,
+    pub B1: f32,
+    pub B2: f32,
+    pub eps: f32,
+    pub t: ::std::os::raw::c_int,
+    pub alpha: f32,
+    pub beta: f32,
+    pub kappa: f32,
+    pub coord_scale: f32,
+    pub object_scale: f32,
+    pub noobject_scale: f32,
+    pub mask_scale: f32,
+    pub class_scale: f32,
+    pub bias_match: ::std::os::raw::c_int,
+    pub random: f32,
+    pub ignore_thresh: f32,
+    pub truth_thresh: f32,
+    pub iou_thresh: f32,
+    pub thresh: f32,
+    pub focus: f32,
+    pub classfix: ::std::os::raw::c_int,
+    pub absolute: ::std::os::raw::c_int,
+    pub assisted_excitation: ::std::os::raw::c_int,
+    pub onlyforward: ::std::os::raw::c_int,
+    pub stopbackward: ::std::os::raw::c_int,
+    pub train_only_bn: ::std::os::raw::c_int,
+    pub dont_update: ::std::os::raw::c_int,
+    pub burnin_update: ::std::os::raw::c_int,
+    pub dontload: ::std::os::raw::c_int,
+    pub dontsave: ::std::os::raw::c_int,
+    pub dontloadscales: ::std::os::raw::c_int,
+    pub numload: ::std::os::raw::c_int,
+    pub temperature: f32,
+    pub probability: f32,
+    pub dropblock_size_rel: f32,
+    pub dropblock_size_abs: ::std::os::raw::c_int,
+    pub dropblock: ::std::os::raw::c_int,
+    pub scale: f32,
+    pub receptive_w: ::std::os::raw::c_int,
+    pub receptive_h: ::std::os::raw::c_int,
+    pub receptive_w_scale: ::std::os::raw::c_int,
+    pub receptive_h_scale: ::std::os::raw::c_int,
+    pub cweights: *mut ::std::os::raw::c_char,
+    pub indexes: *mut ::std::os::raw::c_int,
+    pub input_layers: *mut ::std::os::raw::c_int,
+    pub input_sizes: *mut ::std::os::raw::c_int,
+    pub layers_output: *mut *mut f32,
+    pub layers_delta: *mut *mut f32,
+    pub weights_type: WEIGHTS_TYPE_T,
+    pub weights_normalization: WEIGHTS_NORMALIZATION_T,
+    pub map: *mut ::std::os::raw::c_int,
+    pub counts: *mut ::std::os::raw::c_int,
+    pub sums: *mut *mut f32,
+    pub rand: *mut f32,
+    pub cost: *mut f32,
+    pub labels: *mut ::std::os::raw::c_int,
+    pub class_ids: *mut ::std::os::raw::c_int,
+    pub contrastive_neg_max: ::std::os::raw::c_int,
+    pub cos_sim: *mut f32,
+    pub exp_cos_sim: *mut f32,
+    pub p_constrastive: *mut f32,
+    pub contrast_p_gpu: *mut contrastive_params,
+    pub state: *mut f32,
+    pub prev_state: *mut f32,
+    pub forgot_state: *mut f32,
+    pub forgot_delta: *mut f32,
+    pub state_delta: *mut f32,
+    pub combine_cpu: *mut f32,
+    pub combine_delta_cpu: *mut f32,
+    pub concat: *mut f32,
+    pub concat_delta: *mut f32,
+    pub binary_weights: *mut f32,
+    pub biases: *mut f32,
+    pub bias_updates: *mut f32,
+    pub scales: *mut f32,
+    pub scale_updates: *mut f32,
+    pub weights_ema: *mut f32,
+    pub biases_ema: *mut f32,
+    pub scales_ema: *mut f32,
+    pub weights: *mut f32,
+    pub weight_updates: *mut f32,
+    pub scale_x_y: f32,
+    pub objectness_smooth: ::std::os::raw::c_int,
+    pub new_coords: ::std::os::raw::c_int,
+    pub show_details: ::std::os::raw::c_int,
+    pub max_delta: f32,
+    pub uc_normalizer: f32,
+    pub iou_normalizer: f32,
+    pub obj_normalizer: f32,
+    pub cls_normalizer: f32,
+    pub delta_normalizer: f32,
+    pub iou_loss: IOU_LOSS,
+    pub iou_thresh_kind: IOU_LOSS,
+    pub nms_kind: NMS_KIND,
+    pub beta_nms: f32,
+    pub yolo_point: YOLO_POINT,
+    pub align_bit_weights_gpu: *mut ::std::os::raw::c_char,
+    pub mean_arr_gpu: *mut f32,
+    pub align_workspace_gpu: *mut f32,
+    pub transposed_align_workspace_gpu: *mut f32,
+    pub align_workspace_size: ::std::os::raw::c_int,
+    pub align_bit_weights: *mut ::std::os::raw::c_char,
+    pub mean_arr: *mut f32,
+    pub align_bit_weights_size: ::std::os::raw::c_int,
+    pub lda_align: ::std::os::raw::c_int,
+    pub new_lda: ::std::os::raw::c_int,
+    pub bit_align: ::std::os::raw::c_int,
+    pub col_image: *mut f32,
+    pub delta: *mut f32,
+    pub output: *mut f32,
+    pub activation_input: *mut f32,
+    pub delta_pinned: ::std::os::raw::c_int,
+    pub output_pinned: ::std::os::raw::c_int,
+    pub loss: *mut f32,
+    pub squared: *mut f32,
+    pub norms: *mut f32,
+    pub spatial_mean: *mut f32,
+    pub mean: *mut f32,
+    pub variance: *mut f32,
+    pub mean_delta: *mut f32,
+    pub variance_delta: *mut f32,
+    pub rolling_mean: *mut f32,
+    pub rolling_variance: *mut f32,
+    pub x: *mut f32,
+    pub x_norm: *mut f32,
+    pub m: *mut f32,
+    pub v: *mut f32,
+    pub bias_m: *mut f32,
+    pub bias_v: *mut f32,
+    pub scale_m: *mut f32,
+    pub scale_v: *mut f32,
+    pub z_cpu: *mut f32,
+    pub r_cpu: *mut f32,
+    pub h_cpu: *mut f32,
+    pub stored_h_cpu: *mut f32,
+    pub prev_state_cpu: *mut f32,
+    pub temp_cpu: *mut f32,
+    pub temp2_cpu: *mut f32,
+    pub temp3_cpu: *mut f32,
+    pub dh_cpu: *mut f32,
+    pub hh_cpu: *mut f32,
+    pub prev_cell_cpu: *mut f32,
+    pub cell_cpu: *mut f32,
+    pub f_cpu: *mut f32,
+    pub i_cpu: *mut f32,
+    pub g_cpu: *mut f32,
+    pub o_cpu: *mut f32,
+    pub c_cpu: *mut f32,
+    pub stored_c_cpu: *mut f32,
+    pub dc_cpu: *mut f32,
+    pub binary_input: *mut f32,
+    pub bin_re_packed_input: *mut u32,
+    pub t_bit_input: *mut ::std::os::raw::c_char,
+    pub input_layer: *mut layer,
+    pub self_layer: *mut layer,
+    pub output_layer: *mut layer,
+    pub reset_layer: *mut layer,
+    pub update_layer: *mut layer,
+    pub state_layer: *mut layer,
+    pub input_gate_layer: *mut layer,
+    pub state_gate_layer: *mut layer,
+    pub input_save_layer: *mut layer,
+    pub state_save_layer: *mut layer,
+    pub input_state_layer: *mut layer,
+    pub state_state_layer: *mut layer,
+    pub input_z_layer: *mut layer,
+    pub state_z_layer: *mut layer,
+    pub input_r_layer: *mut layer,
+    pub state_r_layer: *mut layer,
+    pub input_h_layer: *mut layer,
+    pub state_h_layer: *mut layer,
+    pub wz: *mut layer,
+    pub uz: *mut layer,
+    pub wr: *mut layer,
+    pub ur: *mut layer,
+    pub wh: *mut layer,
+    pub uh: *mut layer,
+    pub uo: *mut layer,
+    pub wo: *mut layer,
+    pub vo: *mut layer,
+    pub uf: *mut layer,
+    pub wf: *mut layer,
+    pub vf: *mut layer,
+    pub ui: *mut layer,
+    pub wi: *mut layer,
+    pub vi: *mut layer,
+    pub ug: *mut layer,
+    pub wg: *mut layer,
+    pub softmax_tree: *mut tree,
+    pub workspace_size: usize,
+    pub indexes_gpu: *mut ::std::os::raw::c_int,
+    pub stream: ::std::os::raw::c_int,
+    pub wait_stream_id: ::std::os::raw::c_int,
+    pub z_gpu: *mut f32,
+    pub r_gpu: *mut f32,
+    pub h_gpu: *mut f32,
+    pub stored_h_gpu: *mut f32,
+    pub bottelneck_hi_gpu: *mut f32,
+    pub bottelneck_delta_gpu: *mut f32,
+    pub temp_gpu: *mut f32,
+    pub temp2_gpu: *mut f32,
+    pub temp3_gpu: *mut f32,
+    pub dh_gpu: *mut f32,
+    pub hh_gpu: *mut f32,
+    pub prev_cell_gpu: *mut f32,
+    pub prev_state_gpu: *mut f32,
+    pub last_prev_state_gpu: *mut f32,
+    pub last_prev_cell_gpu: *mut f32,
+    pub cell_gpu: *mut f32,
+    pub f_gpu: *mut f32,
+    pub i_gpu: *mut f32,
+    pub g_gpu: *mut f32,
+    pub o_gpu: *mut f32,
+    pub c_gpu: *mut f32,
+    pub stored_c_gpu: *mut f32,
+    pub dc_gpu: *mut f32,
+    pub m_gpu: *mut f32,
+    pub v_gpu: *mut f32,
+    pub bias_m_gpu: *mut f32,
+    pub scale_m_gpu: *mut f32,
+    pub bias_v_gpu: *mut f32,
+    pub scale_v_gpu: *mut f32,
+    pub combine_gpu: *mut f32,
+    pub combine_delta_gpu: *mut f32,
+    pub forgot_state_gpu: *mut f32,
+    pub forgot_delta_gpu: *mut f32,
+    pub state_gpu: *mut f32,
+    pub state_delta_gpu: *mut f32,
+    pub gate_gpu: *mut f32,
+    pub gate_delta_gpu: *mut f32,
+    pub save_gpu: *mut f32,
+    pub save_delta_gpu: *mut f32,
+    pub concat_gpu: *mut f32,
+    pub concat_delta_gpu: *mut f32,
+    pub binary_input_gpu: *mut f32,
+    pub binary_weights_gpu: *mut f32,
+    pub bin_conv_shortcut_in_gpu: *mut f32,
+    pub bin_conv_shortcut_out_gpu: *mut f32,
+    pub mean_gpu: *mut f32,
+    pub variance_gpu: *mut f32,
+    pub m_cbn_avg_gpu: *mut f32,
+    pub v_cbn_avg_gpu: *mut f32,
+    pub rolling_mean_gpu: *mut f32,
+    pub rolling_variance_gpu: *mut f32,
+    pub variance_delta_gpu: *mut f32,
+    pub mean_delta_gpu: *mut f32,
+    pub col_image_gpu: *mut f32,
+    pub x_gpu: *mut f32,
+    pub x_norm_gpu: *mut f32,
+    pub weights_gpu: *mut f32,
+    pub weight_updates_gpu: *mut f32,
+    pub weight_deform_gpu: *mut f32,
+    pub weight_change_gpu: *mut f32,
+    pub weights_gpu16: *mut f32,
+    pub weight_updates_gpu16: *mut f32,
+    pub biases_gpu: *mut f32,
+    pub bias_updates_gpu: *mut f32,
+    pub bias_change_gpu: *mut f32,
+    pub scales_gpu: *mut f32,
+    pub scale_updates_gpu: *mut f32,
+    pub scale_change_gpu: *mut f32,
+    pub input_antialiasing_gpu: *mut f32,
+    pub output_gpu: *mut f32,
+    pub output_avg_gpu: *mut f32,
+    pub activation_input_gpu: *mut f32,
+    pub loss_gpu: *mut f32,
+    pub delta_gpu: *mut f32,
+    pub cos_sim_gpu: *mut f32,
+    pub rand_gpu: *mut f32,
+    pub drop_blocks_scale: *mut f32,
+    pub drop_blocks_scale_gpu: *mut f32,
+    pub squared_gpu: *mut f32,
+    pub norms_gpu: *mut f32,
+    pub gt_gpu: *mut f32,
+    pub a_avg_gpu: *mut f32,
+    pub input_sizes_gpu: *mut ::std::os::raw::c_int,
+    pub layers_output_gpu: *mut *mut f32,
+    pub layers_delta_gpu: *mut *mut f32,
+    pub srcTensorDesc: *mut ::std::os::raw::c_void,
+    pub dstTensorDesc: *mut ::std::os::raw::c_void,
+    pub srcTensorDesc16: *mut ::std::os::raw::c_void,
+    pub dstTensorDesc16: *mut ::std::os::raw::c_void,
+    pub dsrcTensorDesc: *mut ::std::os::raw::c_void,
+    pub ddstTensorDesc: *mut ::std::os::raw::c_void,
+    pub dsrcTensorDesc16: *mut ::std::os::raw::c_void,
+    pub ddstTensorDesc16: *mut ::std::os::raw::c_void,
+    pub normTensorDesc: *mut ::std::os::raw::c_void,
+    pub normDstTensorDesc: *mut ::std::os::raw::c_void,
+    pub normDstTensorDescF16: *mut ::std::os::raw::c_void,
+    pub weightDesc: *mut ::std::os::raw::c_void,
+    pub weightDesc16: *mut ::std::os::raw::c_void,
+    pub dweightDesc: *mut ::std::os::raw::c_void,
+    pub dweightDesc16: *mut ::std::os::raw::c_void,
+    pub convDesc: *mut ::std::os::raw::c_void,
+    pub fw_algo: UNUSED_ENUM_TYPE,
+    pub fw_algo16: UNUSED_ENUM_TYPE,
+    pub bd_algo: UNUSED_ENUM_TYPE,
+    pub bd_algo16: UNUSED_ENUM_TYPE,
+    pub bf_algo: UNUSED_ENUM_TYPE,
+    pub bf_algo16: UNUSED_ENUM_TYPE,
+    pub poolingDesc: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout_layer() {
+    const UNINIT: ::std::mem::MaybeUninit<layer> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<layer>(),
+        2616usize,
+        concat!("Size of: ", stringify!(layer))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<layer>(),
+        8usize,
+        concat!("Alignment of ", stringify!(layer))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).activation) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(activation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).lstm_activation) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(lstm_activation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cost_type) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cost_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).forward) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(forward)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).backward) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(backward)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).update) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(update)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).forward_gpu) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(forward_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).backward_gpu) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(backward_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).update_gpu) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(update_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).share_layer) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(share_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).train) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(train)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).avgpool) as usize - ptr as usize },
+        76usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(avgpool)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).batch_normalize) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(batch_normalize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).shortcut) as usize - ptr as usize },
+        84usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(shortcut)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).batch) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(batch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dynamic_minibatch) as usize - ptr as usize },
+        92usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dynamic_minibatch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).forced) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(forced)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flipped) as usize - ptr as usize },
+        100usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(flipped)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).inputs) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(inputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).outputs) as usize - ptr as usize },
+        108usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(outputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mean_alpha) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mean_alpha)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nweights) as usize - ptr as usize },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(nweights)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nbiases) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(nbiases)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).extra) as usize - ptr as usize },
+        124usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(extra)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truths) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(truths)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        132usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(h))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        136usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(w))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).c) as usize - ptr as usize },
+        140usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(c))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).out_h) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(out_h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).out_w) as usize - ptr as usize },
+        148usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(out_w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).out_c) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(out_c)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).n) as usize - ptr as usize },
+        156usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(n))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_boxes) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(max_boxes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truth_size) as usize - ptr as usize },
+        164usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(truth_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).groups) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(groups)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).group_id) as usize - ptr as usize },
+        172usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(group_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).side) as usize - ptr as usize },
+        180usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(side)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stride) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stride)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stride_x) as usize - ptr as usize },
+        188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stride_x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stride_y) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stride_y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dilation) as usize - ptr as usize },
+        196usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dilation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).antialiasing) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(antialiasing)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).maxpool_depth) as usize - ptr as usize },
+        204usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(maxpool_depth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).maxpool_zero_nonmax) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(maxpool_zero_nonmax)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).out_channels) as usize - ptr as usize },
+        212usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(out_channels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reverse) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(reverse)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coordconv) as usize - ptr as usize },
+        220usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(coordconv)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flatten) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(flatten)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).spatial) as usize - ptr as usize },
+        228usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(spatial)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).pad) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(pad)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sqrt) as usize - ptr as usize },
+        236usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(sqrt)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flip) as usize - ptr as usize },
+        240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(flip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).index) as usize - ptr as usize },
+        244usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_wh) as usize - ptr as usize },
+        248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_wh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).binary) as usize - ptr as usize },
+        252usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(binary)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).xnor) as usize - ptr as usize },
+        256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(xnor)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).peephole) as usize - ptr as usize },
+        260usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(peephole)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).use_bin_output) as usize - ptr as usize },
+        264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(use_bin_output)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).keep_delta_gpu) as usize - ptr as usize },
+        268usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(keep_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).optimized_memory) as usize - ptr as usize },
+        272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(optimized_memory)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).steps) as usize - ptr as usize },
+        276usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(steps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).history_size) as usize - ptr as usize },
+        280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(history_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bottleneck) as usize - ptr as usize },
+        284usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bottleneck)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_normalizer) as usize - ptr as usize },
+        288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(time_normalizer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_constrain) as usize - ptr as usize },
+        292usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_constrain)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hidden) as usize - ptr as usize },
+        296usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(hidden)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truth) as usize - ptr as usize },
+        300usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(truth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).smooth) as usize - ptr as usize },
+        304usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(smooth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dot) as usize - ptr as usize },
+        308usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dot)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).deform) as usize - ptr as usize },
+        312usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(deform)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).grad_centr) as usize - ptr as usize },
+        316usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(grad_centr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sway) as usize - ptr as usize },
+        320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(sway)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rotate) as usize - ptr as usize },
+        324usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rotate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stretch) as usize - ptr as usize },
+        328usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stretch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stretch_sway) as usize - ptr as usize },
+        332usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stretch_sway)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).angle) as usize - ptr as usize },
+        336usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(angle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).jitter) as usize - ptr as usize },
+        340usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(jitter)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).resize) as usize - ptr as usize },
+        344usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(resize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).saturation) as usize - ptr as usize },
+        348usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(saturation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).exposure) as usize - ptr as usize },
+        352usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(exposure)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).shift) as usize - ptr as usize },
+        356usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(shift)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ratio) as usize - ptr as usize },
+        360usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).learning_rate_scale) as usize - ptr as usize },
+        364usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(learning_rate_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).clip) as usize - ptr as usize },
+        368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(clip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).focal_loss) as usize - ptr as usize },
+        372usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(focal_loss)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classes_multipliers) as usize - ptr as usize },
+        376usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(classes_multipliers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).label_smooth_eps) as usize - ptr as usize },
+        384usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(label_smooth_eps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).noloss) as usize - ptr as usize },
+        388usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(noloss)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).softmax) as usize - ptr as usize },
+        392usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(softmax)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classes) as usize - ptr as usize },
+        396usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(classes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).detection) as usize - ptr as usize },
+        400usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(detection)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).embedding_layer_id) as usize - ptr as usize },
+        404usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(embedding_layer_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).embedding_output) as usize - ptr as usize },
+        408usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(embedding_output)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).embedding_size) as usize - ptr as usize },
+        416usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(embedding_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sim_thresh) as usize - ptr as usize },
+        420usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(sim_thresh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).track_history_size) as usize - ptr as usize },
+        424usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(track_history_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dets_for_track) as usize - ptr as usize },
+        428usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dets_for_track)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dets_for_show) as usize - ptr as usize },
+        432usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dets_for_show)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).track_ciou_norm) as usize - ptr as usize },
+        436usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(track_ciou_norm)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coords) as usize - ptr as usize },
+        440usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(coords)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).background) as usize - ptr as usize },
+        444usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(background)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rescore) as usize - ptr as usize },
+        448usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rescore)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).objectness) as usize - ptr as usize },
+        452usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(objectness)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).does_cost) as usize - ptr as usize },
+        456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(does_cost)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).joint) as usize - ptr as usize },
+        460usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(joint)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).noadjust) as usize - ptr as usize },
+        464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(noadjust)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reorg) as usize - ptr as usize },
+        468usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(reorg)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).log) as usize - ptr as usize },
+        472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(log)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).tanh) as usize - ptr as usize },
+        476usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(tanh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mask) as usize - ptr as usize },
+        480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mask)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).total) as usize - ptr as usize },
+        488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(total)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bflops) as usize - ptr as usize },
+        492usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bflops)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).adam) as usize - ptr as usize },
+        496usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(adam)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).B1) as usize - ptr as usize },
+        500usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(B1))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).B2) as usize - ptr as usize },
+        504usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(B2))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).eps) as usize - ptr as usize },
+        508usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(eps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).t) as usize - ptr as usize },
+        512usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(t))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).alpha) as usize - ptr as usize },
+        516usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(alpha)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).beta) as usize - ptr as usize },
+        520usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(beta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).kappa) as usize - ptr as usize },
+        524usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(kappa)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coord_scale) as usize - ptr as usize },
+        528usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(coord_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).object_scale) as usize - ptr as usize },
+        532usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(object_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).noobject_scale) as usize - ptr as usize },
+        536usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(noobject_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mask_scale) as usize - ptr as usize },
+        540usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mask_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).class_scale) as usize - ptr as usize },
+        544usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(class_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_match) as usize - ptr as usize },
+        548usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_match)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).random) as usize - ptr as usize },
+        552usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(random)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ignore_thresh) as usize - ptr as usize },
+        556usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(ignore_thresh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truth_thresh) as usize - ptr as usize },
+        560usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(truth_thresh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).iou_thresh) as usize - ptr as usize },
+        564usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(iou_thresh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).thresh) as usize - ptr as usize },
+        568usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(thresh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).focus) as usize - ptr as usize },
+        572usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(focus)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classfix) as usize - ptr as usize },
+        576usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(classfix)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).absolute) as usize - ptr as usize },
+        580usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(absolute)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).assisted_excitation) as usize - ptr as usize },
+        584usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(assisted_excitation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).onlyforward) as usize - ptr as usize },
+        588usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(onlyforward)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stopbackward) as usize - ptr as usize },
+        592usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stopbackward)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).train_only_bn) as usize - ptr as usize },
+        596usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(train_only_bn)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dont_update) as usize - ptr as usize },
+        600usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dont_update)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).burnin_update) as usize - ptr as usize },
+        604usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(burnin_update)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dontload) as usize - ptr as usize },
+        608usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dontload)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dontsave) as usize - ptr as usize },
+        612usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dontsave)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dontloadscales) as usize - ptr as usize },
+        616usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dontloadscales)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).numload) as usize - ptr as usize },
+        620usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(numload)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temperature) as usize - ptr as usize },
+        624usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(temperature)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).probability) as usize - ptr as usize },
+        628usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(probability)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dropblock_size_rel) as usize - ptr as usize },
+        632usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dropblock_size_rel)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dropblock_size_abs) as usize - ptr as usize },
+        636usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dropblock_size_abs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dropblock) as usize - ptr as usize },
+        640usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dropblock)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale) as usize - ptr as usize },
+        644usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).receptive_w) as usize - ptr as usize },
+        648usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(receptive_w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).receptive_h) as usize - ptr as usize },
+        652usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(receptive_h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).receptive_w_scale) as usize - ptr as usize },
+        656usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(receptive_w_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).receptive_h_scale) as usize - ptr as usize },
+        660usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(receptive_h_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cweights) as usize - ptr as usize },
+        664usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cweights)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).indexes) as usize - ptr as usize },
+        672usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(indexes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_layers) as usize - ptr as usize },
+        680usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_layers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_sizes) as usize - ptr as usize },
+        688usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_sizes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).layers_output) as usize - ptr as usize },
+        696usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(layers_output)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).layers_delta) as usize - ptr as usize },
+        704usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(layers_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weights_type) as usize - ptr as usize },
+        712usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weights_type)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weights_normalization) as usize - ptr as usize },
+        716usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weights_normalization)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).map) as usize - ptr as usize },
+        720usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(map)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).counts) as usize - ptr as usize },
+        728usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(counts)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sums) as usize - ptr as usize },
+        736usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(sums)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rand) as usize - ptr as usize },
+        744usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rand)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cost) as usize - ptr as usize },
+        752usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cost)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).labels) as usize - ptr as usize },
+        760usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(labels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).class_ids) as usize - ptr as usize },
+        768usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(class_ids)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrastive_neg_max) as usize - ptr as usize },
+        776usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(contrastive_neg_max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cos_sim) as usize - ptr as usize },
+        784usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cos_sim)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).exp_cos_sim) as usize - ptr as usize },
+        792usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(exp_cos_sim)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).p_constrastive) as usize - ptr as usize },
+        800usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(p_constrastive)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrast_p_gpu) as usize - ptr as usize },
+        808usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(contrast_p_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state) as usize - ptr as usize },
+        816usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prev_state) as usize - ptr as usize },
+        824usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(prev_state)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).forgot_state) as usize - ptr as usize },
+        832usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(forgot_state)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).forgot_delta) as usize - ptr as usize },
+        840usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(forgot_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_delta) as usize - ptr as usize },
+        848usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).combine_cpu) as usize - ptr as usize },
+        856usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(combine_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).combine_delta_cpu) as usize - ptr as usize },
+        864usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(combine_delta_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).concat) as usize - ptr as usize },
+        872usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(concat)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).concat_delta) as usize - ptr as usize },
+        880usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(concat_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).binary_weights) as usize - ptr as usize },
+        888usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(binary_weights)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).biases) as usize - ptr as usize },
+        896usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(biases)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_updates) as usize - ptr as usize },
+        904usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_updates)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scales) as usize - ptr as usize },
+        912usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scales)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_updates) as usize - ptr as usize },
+        920usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_updates)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weights_ema) as usize - ptr as usize },
+        928usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weights_ema)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).biases_ema) as usize - ptr as usize },
+        936usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(biases_ema)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scales_ema) as usize - ptr as usize },
+        944usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scales_ema)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weights) as usize - ptr as usize },
+        952usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weights)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weight_updates) as usize - ptr as usize },
+        960usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weight_updates)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_x_y) as usize - ptr as usize },
+        968usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_x_y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).objectness_smooth) as usize - ptr as usize },
+        972usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(objectness_smooth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).new_coords) as usize - ptr as usize },
+        976usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(new_coords)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).show_details) as usize - ptr as usize },
+        980usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(show_details)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_delta) as usize - ptr as usize },
+        984usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(max_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uc_normalizer) as usize - ptr as usize },
+        988usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(uc_normalizer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).iou_normalizer) as usize - ptr as usize },
+        992usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(iou_normalizer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).obj_normalizer) as usize - ptr as usize },
+        996usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(obj_normalizer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cls_normalizer) as usize - ptr as usize },
+        1000usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cls_normalizer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta_normalizer) as usize - ptr as usize },
+        1004usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(delta_normalizer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).iou_loss) as usize - ptr as usize },
+        1008usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(iou_loss)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).iou_thresh_kind) as usize - ptr as usize },
+        1012usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(iou_thresh_kind)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nms_kind) as usize - ptr as usize },
+        1016usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(nms_kind)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).beta_nms) as usize - ptr as usize },
+        1020usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(beta_nms)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).yolo_point) as usize - ptr as usize },
+        1024usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(yolo_point)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).align_bit_weights_gpu) as usize - ptr as usize },
+        1032usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(align_bit_weights_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mean_arr_gpu) as usize - ptr as usize },
+        1040usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mean_arr_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).align_workspace_gpu) as usize - ptr as usize },
+        1048usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(align_workspace_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).transposed_align_workspace_gpu) as usize - ptr as usize
+        },
+        1056usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(transposed_align_workspace_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).align_workspace_size) as usize - ptr as usize },
+        1064usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(align_workspace_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).align_bit_weights) as usize - ptr as usize },
+        1072usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(align_bit_weights)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mean_arr) as usize - ptr as usize },
+        1080usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mean_arr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).align_bit_weights_size) as usize - ptr as usize },
+        1088usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(align_bit_weights_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).lda_align) as usize - ptr as usize },
+        1092usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(lda_align)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).new_lda) as usize - ptr as usize },
+        1096usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(new_lda)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bit_align) as usize - ptr as usize },
+        1100usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bit_align)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).col_image) as usize - ptr as usize },
+        1104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(col_image)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta) as usize - ptr as usize },
+        1112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output) as usize - ptr as usize },
+        1120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(output)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).activation_input) as usize - ptr as usize },
+        1128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(activation_input)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta_pinned) as usize - ptr as usize },
+        1136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(delta_pinned)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_pinned) as usize - ptr as usize },
+        1140usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(output_pinned)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).loss) as usize - ptr as usize },
+        1144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(loss)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).squared) as usize - ptr as usize },
+        1152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(squared)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).norms) as usize - ptr as usize },
+        1160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(norms)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).spatial_mean) as usize - ptr as usize },
+        1168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(spatial_mean)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mean) as usize - ptr as usize },
+        1176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mean)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).variance) as usize - ptr as usize },
+        1184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(variance)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mean_delta) as usize - ptr as usize },
+        1192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mean_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).variance_delta) as usize - ptr as usize },
+        1200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(variance_delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rolling_mean) as usize - ptr as usize },
+        1208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rolling_mean)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rolling_variance) as usize - ptr as usize },
+        1216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rolling_variance)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        1224usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(x))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x_norm) as usize - ptr as usize },
+        1232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(x_norm)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).m) as usize - ptr as usize },
+        1240usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(m))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).v) as usize - ptr as usize },
+        1248usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(v))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_m) as usize - ptr as usize },
+        1256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_m)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_v) as usize - ptr as usize },
+        1264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_v)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_m) as usize - ptr as usize },
+        1272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_m)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_v) as usize - ptr as usize },
+        1280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_v)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).z_cpu) as usize - ptr as usize },
+        1288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(z_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).r_cpu) as usize - ptr as usize },
+        1296usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(r_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h_cpu) as usize - ptr as usize },
+        1304usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(h_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stored_h_cpu) as usize - ptr as usize },
+        1312usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stored_h_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prev_state_cpu) as usize - ptr as usize },
+        1320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(prev_state_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temp_cpu) as usize - ptr as usize },
+        1328usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(temp_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temp2_cpu) as usize - ptr as usize },
+        1336usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(temp2_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temp3_cpu) as usize - ptr as usize },
+        1344usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(temp3_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dh_cpu) as usize - ptr as usize },
+        1352usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dh_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hh_cpu) as usize - ptr as usize },
+        1360usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(hh_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prev_cell_cpu) as usize - ptr as usize },
+        1368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(prev_cell_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cell_cpu) as usize - ptr as usize },
+        1376usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cell_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).f_cpu) as usize - ptr as usize },
+        1384usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(f_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i_cpu) as usize - ptr as usize },
+        1392usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(i_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).g_cpu) as usize - ptr as usize },
+        1400usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(g_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).o_cpu) as usize - ptr as usize },
+        1408usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(o_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).c_cpu) as usize - ptr as usize },
+        1416usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(c_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stored_c_cpu) as usize - ptr as usize },
+        1424usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stored_c_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dc_cpu) as usize - ptr as usize },
+        1432usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dc_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).binary_input) as usize - ptr as usize },
+        1440usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(binary_input)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bin_re_packed_input) as usize - ptr as usize },
+        1448usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bin_re_packed_input)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).t_bit_input) as usize - ptr as usize },
+        1456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(t_bit_input)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_layer) as usize - ptr as usize },
+        1464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).self_layer) as usize - ptr as usize },
+        1472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(self_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_layer) as usize - ptr as usize },
+        1480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(output_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).reset_layer) as usize - ptr as usize },
+        1488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(reset_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).update_layer) as usize - ptr as usize },
+        1496usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(update_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_layer) as usize - ptr as usize },
+        1504usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_gate_layer) as usize - ptr as usize },
+        1512usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_gate_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_gate_layer) as usize - ptr as usize },
+        1520usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_gate_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_save_layer) as usize - ptr as usize },
+        1528usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_save_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_save_layer) as usize - ptr as usize },
+        1536usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_save_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_state_layer) as usize - ptr as usize },
+        1544usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_state_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_state_layer) as usize - ptr as usize },
+        1552usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_state_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_z_layer) as usize - ptr as usize },
+        1560usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_z_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_z_layer) as usize - ptr as usize },
+        1568usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_z_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_r_layer) as usize - ptr as usize },
+        1576usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_r_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_r_layer) as usize - ptr as usize },
+        1584usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_r_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_h_layer) as usize - ptr as usize },
+        1592usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_h_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_h_layer) as usize - ptr as usize },
+        1600usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_h_layer)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wz) as usize - ptr as usize },
+        1608usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(wz))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uz) as usize - ptr as usize },
+        1616usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(uz))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wr) as usize - ptr as usize },
+        1624usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(wr))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ur) as usize - ptr as usize },
+        1632usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(ur))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wh) as usize - ptr as usize },
+        1640usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(wh))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uh) as usize - ptr as usize },
+        1648usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(uh))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uo) as usize - ptr as usize },
+        1656usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(uo))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wo) as usize - ptr as usize },
+        1664usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(wo))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vo) as usize - ptr as usize },
+        1672usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(vo))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uf) as usize - ptr as usize },
+        1680usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(uf))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wf) as usize - ptr as usize },
+        1688usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(wf))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vf) as usize - ptr as usize },
+        1696usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(vf))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ui) as usize - ptr as usize },
+        1704usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(ui))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wi) as usize - ptr as usize },
+        1712usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(wi))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vi) as usize - ptr as usize },
+        1720usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(vi))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ug) as usize - ptr as usize },
+        1728usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(ug))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wg) as usize - ptr as usize },
+        1736usize,
+        concat!("Offset of field: ", stringify!(layer), "::", stringify!(wg))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).softmax_tree) as usize - ptr as usize },
+        1744usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(softmax_tree)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).workspace_size) as usize - ptr as usize },
+        1752usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(workspace_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).indexes_gpu) as usize - ptr as usize },
+        1760usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(indexes_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stream) as usize - ptr as usize },
+        1768usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stream)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wait_stream_id) as usize - ptr as usize },
+        1772usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(wait_stream_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).z_gpu) as usize - ptr as usize },
+        1776usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(z_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).r_gpu) as usize - ptr as usize },
+        1784usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(r_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h_gpu) as usize - ptr as usize },
+        1792usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(h_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stored_h_gpu) as usize - ptr as usize },
+        1800usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stored_h_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bottelneck_hi_gpu) as usize - ptr as usize },
+        1808usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bottelneck_hi_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bottelneck_delta_gpu) as usize - ptr as usize },
+        1816usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bottelneck_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temp_gpu) as usize - ptr as usize },
+        1824usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(temp_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temp2_gpu) as usize - ptr as usize },
+        1832usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(temp2_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).temp3_gpu) as usize - ptr as usize },
+        1840usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(temp3_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dh_gpu) as usize - ptr as usize },
+        1848usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dh_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hh_gpu) as usize - ptr as usize },
+        1856usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(hh_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prev_cell_gpu) as usize - ptr as usize },
+        1864usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(prev_cell_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prev_state_gpu) as usize - ptr as usize },
+        1872usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(prev_state_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_prev_state_gpu) as usize - ptr as usize },
+        1880usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(last_prev_state_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).last_prev_cell_gpu) as usize - ptr as usize },
+        1888usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(last_prev_cell_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cell_gpu) as usize - ptr as usize },
+        1896usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cell_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).f_gpu) as usize - ptr as usize },
+        1904usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(f_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).i_gpu) as usize - ptr as usize },
+        1912usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(i_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).g_gpu) as usize - ptr as usize },
+        1920usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(g_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).o_gpu) as usize - ptr as usize },
+        1928usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(o_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).c_gpu) as usize - ptr as usize },
+        1936usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(c_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).stored_c_gpu) as usize - ptr as usize },
+        1944usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(stored_c_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dc_gpu) as usize - ptr as usize },
+        1952usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dc_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).m_gpu) as usize - ptr as usize },
+        1960usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(m_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).v_gpu) as usize - ptr as usize },
+        1968usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(v_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_m_gpu) as usize - ptr as usize },
+        1976usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_m_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_m_gpu) as usize - ptr as usize },
+        1984usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_m_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_v_gpu) as usize - ptr as usize },
+        1992usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_v_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_v_gpu) as usize - ptr as usize },
+        2000usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_v_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).combine_gpu) as usize - ptr as usize },
+        2008usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(combine_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).combine_delta_gpu) as usize - ptr as usize },
+        2016usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(combine_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).forgot_state_gpu) as usize - ptr as usize },
+        2024usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(forgot_state_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).forgot_delta_gpu) as usize - ptr as usize },
+        2032usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(forgot_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_gpu) as usize - ptr as usize },
+        2040usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_delta_gpu) as usize - ptr as usize },
+        2048usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(state_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gate_gpu) as usize - ptr as usize },
+        2056usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(gate_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gate_delta_gpu) as usize - ptr as usize },
+        2064usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(gate_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).save_gpu) as usize - ptr as usize },
+        2072usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(save_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).save_delta_gpu) as usize - ptr as usize },
+        2080usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(save_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).concat_gpu) as usize - ptr as usize },
+        2088usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(concat_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).concat_delta_gpu) as usize - ptr as usize },
+        2096usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(concat_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).binary_input_gpu) as usize - ptr as usize },
+        2104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(binary_input_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).binary_weights_gpu) as usize - ptr as usize },
+        2112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(binary_weights_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bin_conv_shortcut_in_gpu) as usize - ptr as usize },
+        2120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bin_conv_shortcut_in_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bin_conv_shortcut_out_gpu) as usize - ptr as usize },
+        2128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bin_conv_shortcut_out_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mean_gpu) as usize - ptr as usize },
+        2136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mean_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).variance_gpu) as usize - ptr as usize },
+        2144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(variance_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).m_cbn_avg_gpu) as usize - ptr as usize },
+        2152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(m_cbn_avg_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).v_cbn_avg_gpu) as usize - ptr as usize },
+        2160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(v_cbn_avg_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rolling_mean_gpu) as usize - ptr as usize },
+        2168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rolling_mean_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rolling_variance_gpu) as usize - ptr as usize },
+        2176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rolling_variance_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).variance_delta_gpu) as usize - ptr as usize },
+        2184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(variance_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mean_delta_gpu) as usize - ptr as usize },
+        2192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(mean_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).col_image_gpu) as usize - ptr as usize },
+        2200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(col_image_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x_gpu) as usize - ptr as usize },
+        2208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(x_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x_norm_gpu) as usize - ptr as usize },
+        2216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(x_norm_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weights_gpu) as usize - ptr as usize },
+        2224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weights_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weight_updates_gpu) as usize - ptr as usize },
+        2232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weight_updates_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weight_deform_gpu) as usize - ptr as usize },
+        2240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weight_deform_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weight_change_gpu) as usize - ptr as usize },
+        2248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weight_change_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weights_gpu16) as usize - ptr as usize },
+        2256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weights_gpu16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weight_updates_gpu16) as usize - ptr as usize },
+        2264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weight_updates_gpu16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).biases_gpu) as usize - ptr as usize },
+        2272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(biases_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_updates_gpu) as usize - ptr as usize },
+        2280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_updates_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bias_change_gpu) as usize - ptr as usize },
+        2288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bias_change_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scales_gpu) as usize - ptr as usize },
+        2296usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scales_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_updates_gpu) as usize - ptr as usize },
+        2304usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_updates_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale_change_gpu) as usize - ptr as usize },
+        2312usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(scale_change_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_antialiasing_gpu) as usize - ptr as usize },
+        2320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_antialiasing_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_gpu) as usize - ptr as usize },
+        2328usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(output_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_avg_gpu) as usize - ptr as usize },
+        2336usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(output_avg_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).activation_input_gpu) as usize - ptr as usize },
+        2344usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(activation_input_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).loss_gpu) as usize - ptr as usize },
+        2352usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(loss_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta_gpu) as usize - ptr as usize },
+        2360usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cos_sim_gpu) as usize - ptr as usize },
+        2368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(cos_sim_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rand_gpu) as usize - ptr as usize },
+        2376usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(rand_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).drop_blocks_scale) as usize - ptr as usize },
+        2384usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(drop_blocks_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).drop_blocks_scale_gpu) as usize - ptr as usize },
+        2392usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(drop_blocks_scale_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).squared_gpu) as usize - ptr as usize },
+        2400usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(squared_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).norms_gpu) as usize - ptr as usize },
+        2408usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(norms_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gt_gpu) as usize - ptr as usize },
+        2416usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(gt_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).a_avg_gpu) as usize - ptr as usize },
+        2424usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(a_avg_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_sizes_gpu) as usize - ptr as usize },
+        2432usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(input_sizes_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).layers_output_gpu) as usize - ptr as usize },
+        2440usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(layers_output_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).layers_delta_gpu) as usize - ptr as usize },
+        2448usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(layers_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).srcTensorDesc) as usize - ptr as usize },
+        2456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(srcTensorDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dstTensorDesc) as usize - ptr as usize },
+        2464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dstTensorDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).srcTensorDesc16) as usize - ptr as usize },
+        2472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(srcTensorDesc16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dstTensorDesc16) as usize - ptr as usize },
+        2480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dstTensorDesc16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dsrcTensorDesc) as usize - ptr as usize },
+        2488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dsrcTensorDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ddstTensorDesc) as usize - ptr as usize },
+        2496usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(ddstTensorDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dsrcTensorDesc16) as usize - ptr as usize },
+        2504usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dsrcTensorDesc16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ddstTensorDesc16) as usize - ptr as usize },
+        2512usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(ddstTensorDesc16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).normTensorDesc) as usize - ptr as usize },
+        2520usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(normTensorDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).normDstTensorDesc) as usize - ptr as usize },
+        2528usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(normDstTensorDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).normDstTensorDescF16) as usize - ptr as usize },
+        2536usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(normDstTensorDescF16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weightDesc) as usize - ptr as usize },
+        2544usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weightDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weightDesc16) as usize - ptr as usize },
+        2552usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(weightDesc16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dweightDesc) as usize - ptr as usize },
+        2560usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dweightDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dweightDesc16) as usize - ptr as usize },
+        2568usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(dweightDesc16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).convDesc) as usize - ptr as usize },
+        2576usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(convDesc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).fw_algo) as usize - ptr as usize },
+        2584usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(fw_algo)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).fw_algo16) as usize - ptr as usize },
+        2588usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(fw_algo16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bd_algo) as usize - ptr as usize },
+        2592usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bd_algo)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bd_algo16) as usize - ptr as usize },
+        2596usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bd_algo16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bf_algo) as usize - ptr as usize },
+        2600usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bf_algo)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bf_algo16) as usize - ptr as usize },
+        2604usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(bf_algo16)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).poolingDesc) as usize - ptr as usize },
+        2608usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(layer),
+            "::",
+            stringify!(poolingDesc)
+        )
+    );
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum learning_rate_policy {
+    CONSTANT = 0,
+    STEP = 1,
+    EXP = 2,
+    POLY = 3,
+    STEPS = 4,
+    SIG = 5,
+    RANDOM = 6,
+    SGDR = 7,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct network {
+    pub n: ::std::os::raw::c_int,
+    pub batch: ::std::os::raw::c_int,
+    pub seen: *mut u64,
+    pub badlabels_reject_threshold: *mut f32,
+    pub delta_rolling_max: *mut f32,
+    pub delta_rolling_avg: *mut f32,
+    pub delta_rolling_std: *mut f32,
+    pub weights_reject_freq: ::std::os::raw::c_int,
+    pub equidistant_point: ::std::os::raw::c_int,
+    pub badlabels_rejection_percentage: f32,
+    pub num_sigmas_reject_badlabels: f32,
+    pub ema_alpha: f32,
+    pub cur_iteration: *mut ::std::os::raw::c_int,
+    pub loss_scale: f32,
+    pub t: *mut ::std::os::raw::c_int,
+    pub epoch: f32,
+    pub subdivisions: ::std::os::raw::c_int,
+    pub layers: *mut layer,
+    pub output: *mut f32,
+    pub policy: learning_rate_policy,
+    pub benchmark_layers: ::std::os::raw::c_int,
+    pub total_bbox: *mut ::std::os::raw::c_int,
+    pub rewritten_bbox: *mut ::std::os::raw::c_int,
+    pub learning_rate: f32,
+    pub learning_rate_min: f32,
+    pub learning_rate_max: f32,
+    pub batches_per_cycle: ::std::os::raw::c_int,
+    pub batches_cycle_mult: ::std::os::raw::c_int,
+    pub momentum: f32,
+    pub decay: f32,
+    pub gamma: f32,
+    pub scale: f32,
+    pub power: f32,
+    pub time_steps: ::std::os::raw::c_int,
+    pub step: ::std::os::raw::c_int,
+    pub max_batches: ::std::os::raw::c_int,
+    pub num_boxes: ::std::os::raw::c_int,
+    pub train_images_num: ::std::os::raw::c_int,
+    pub seq_scales: *mut f32,
+    pub scales: *mut f32,
+    pub steps: *mut ::std::os::raw::c_int,
+    pub num_steps: ::std::os::raw::c_int,
+    pub burn_in: ::std::os::raw::c_int,
+    pub cudnn_half: ::std::os::raw::c_int,
+    pub adam: ::std::os::raw::c_int,
+    pub B1: f32,
+    pub B2: f32,
+    pub eps: f32,
+    pub inputs: ::std::os::raw::c_int,
+    pub outputs: ::std::os::raw::c_int,
+    pub truths: ::std::os::raw::c_int,
+    pub notruth: ::std::os::raw::c_int,
+    pub h: ::std::os::raw::c_int,
+    pub w: ::std::os::raw::c_int,
+    pub c: ::std::os::raw::c_int,
+    pub max_crop: ::std::os::raw::c_int,
+    pub min_crop: ::std::os::raw::c_int,
+    pub max_ratio: f32,
+    pub min_ratio: f32,
+    pub center: ::std::os::raw::c_int,
+    pub flip: ::std::os::raw::c_int,
+    pub gaussian_noise: ::std::os::raw::c_int,
+    pub blur: ::std::os::raw::c_int,
+    pub mixup: ::std::os::raw::c_int,
+    pub label_smooth_eps: f32,
+    pub resize_step: ::std::os::raw::c_int,
+    pub attention: ::std::os::raw::c_int,
+    pub adversarial: ::std::os::raw::c_int,
+    pub adversarial_lr: f32,
+    pub max_chart_loss: f32,
+    pub letter_box: ::std::os::raw::c_int,
+    pub mosaic_bound: ::std::os::raw::c_int,
+    pub contrastive: ::std::os::raw::c_int,
+    pub contrastive_jit_flip: ::std::os::raw::c_int,
+    pub contrastive_color: ::std::os::raw::c_int,
+    pub unsupervised: ::std::os::raw::c_int,
+    pub angle: f32,
+    pub aspect: f32,
+    pub exposure: f32,
+    pub saturation: f32,
+    pub hue: f32,
+    pub random: ::std::os::raw::c_int,
+    pub track: ::std::os::raw::c_int,
+    pub augment_speed: ::std::os::raw::c_int,
+    pub sequential_subdivisions: ::std::os::raw::c_int,
+    pub init_sequential_subdivisions: ::std::os::raw::c_int,
+    pub current_subdivision: ::std::os::raw::c_int,
+    pub try_fix_nan: ::std::os::raw::c_int,
+    pub gpu_index: ::std::os::raw::c_int,
+    pub hierarchy: *mut tree,
+    pub input: *mut f32,
+    pub truth: *mut f32,
+    pub delta: *mut f32,
+    pub workspace: *mut f32,
+    pub train: ::std::os::raw::c_int,
+    pub index: ::std::os::raw::c_int,
+    pub cost: *mut f32,
+    pub clip: f32,
+    pub delta_gpu: *mut f32,
+    pub output_gpu: *mut f32,
+    pub input_state_gpu: *mut f32,
+    pub input_pinned_cpu: *mut f32,
+    pub input_pinned_cpu_flag: ::std::os::raw::c_int,
+    pub input_gpu: *mut *mut f32,
+    pub truth_gpu: *mut *mut f32,
+    pub input16_gpu: *mut *mut f32,
+    pub output16_gpu: *mut *mut f32,
+    pub max_input16_size: *mut usize,
+    pub max_output16_size: *mut usize,
+    pub wait_stream: ::std::os::raw::c_int,
+    pub cuda_graph: *mut ::std::os::raw::c_void,
+    pub cuda_graph_exec: *mut ::std::os::raw::c_void,
+    pub use_cuda_graph: ::std::os::raw::c_int,
+    pub cuda_graph_ready: *mut ::std::os::raw::c_int,
+    pub global_delta_gpu: *mut f32,
+    pub state_delta_gpu: *mut f32,
+    pub max_delta_gpu_size: usize,
+    pub optimized_memory: ::std::os::raw::c_int,
+    pub dynamic_minibatch: ::std::os::raw::c_int,
+    pub workspace_size_limit: usize,
+}
+#[test]
+fn bindgen_test_layout_network() {
+    const UNINIT: ::std::mem::MaybeUninit<network> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<network>(),
+        656usize,
+        concat!("Size of: ", stringify!(network))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<network>(),
+        8usize,
+        concat!("Alignment of ", stringify!(network))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).n) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(n)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).batch) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(batch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seen) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(seen)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).badlabels_reject_threshold) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(badlabels_reject_threshold)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta_rolling_max) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(delta_rolling_max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta_rolling_avg) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(delta_rolling_avg)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta_rolling_std) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(delta_rolling_std)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).weights_reject_freq) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(weights_reject_freq)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).equidistant_point) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(equidistant_point)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).badlabels_rejection_percentage) as usize - ptr as usize
+        },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(badlabels_rejection_percentage)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num_sigmas_reject_badlabels) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(num_sigmas_reject_badlabels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ema_alpha) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(ema_alpha)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cur_iteration) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(cur_iteration)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).loss_scale) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(loss_scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).t) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(t)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).epoch) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(epoch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).subdivisions) as usize - ptr as usize },
+        100usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(subdivisions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).layers) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(layers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(output)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).policy) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(policy)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).benchmark_layers) as usize - ptr as usize },
+        124usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(benchmark_layers)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).total_bbox) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(total_bbox)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rewritten_bbox) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(rewritten_bbox)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).learning_rate) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(learning_rate)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).learning_rate_min) as usize - ptr as usize },
+        148usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(learning_rate_min)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).learning_rate_max) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(learning_rate_max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).batches_per_cycle) as usize - ptr as usize },
+        156usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(batches_per_cycle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).batches_cycle_mult) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(batches_cycle_mult)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).momentum) as usize - ptr as usize },
+        164usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(momentum)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).decay) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(decay)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gamma) as usize - ptr as usize },
+        172usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(gamma)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).power) as usize - ptr as usize },
+        180usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(power)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).time_steps) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(time_steps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).step) as usize - ptr as usize },
+        188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(step)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_batches) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(max_batches)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num_boxes) as usize - ptr as usize },
+        196usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(num_boxes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).train_images_num) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(train_images_num)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).seq_scales) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(seq_scales)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scales) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(scales)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).steps) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(steps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num_steps) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(num_steps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).burn_in) as usize - ptr as usize },
+        236usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(burn_in)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cudnn_half) as usize - ptr as usize },
+        240usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(cudnn_half)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).adam) as usize - ptr as usize },
+        244usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(adam)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).B1) as usize - ptr as usize },
+        248usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(B1)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).B2) as usize - ptr as usize },
+        252usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(B2)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).eps) as usize - ptr as usize },
+        256usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(eps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).inputs) as usize - ptr as usize },
+        260usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(inputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).outputs) as usize - ptr as usize },
+        264usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(outputs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truths) as usize - ptr as usize },
+        268usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(truths)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).notruth) as usize - ptr as usize },
+        272usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(notruth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        276usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        280usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).c) as usize - ptr as usize },
+        284usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(c)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_crop) as usize - ptr as usize },
+        288usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(max_crop)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_crop) as usize - ptr as usize },
+        292usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(min_crop)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_ratio) as usize - ptr as usize },
+        296usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(max_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min_ratio) as usize - ptr as usize },
+        300usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(min_ratio)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).center) as usize - ptr as usize },
+        304usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(center)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flip) as usize - ptr as usize },
+        308usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(flip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gaussian_noise) as usize - ptr as usize },
+        312usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(gaussian_noise)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).blur) as usize - ptr as usize },
+        316usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(blur)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mixup) as usize - ptr as usize },
+        320usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(mixup)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).label_smooth_eps) as usize - ptr as usize },
+        324usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(label_smooth_eps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).resize_step) as usize - ptr as usize },
+        328usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(resize_step)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).attention) as usize - ptr as usize },
+        332usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(attention)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).adversarial) as usize - ptr as usize },
+        336usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(adversarial)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).adversarial_lr) as usize - ptr as usize },
+        340usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(adversarial_lr)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_chart_loss) as usize - ptr as usize },
+        344usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(max_chart_loss)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).letter_box) as usize - ptr as usize },
+        348usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(letter_box)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mosaic_bound) as usize - ptr as usize },
+        352usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(mosaic_bound)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrastive) as usize - ptr as usize },
+        356usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(contrastive)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrastive_jit_flip) as usize - ptr as usize },
+        360usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(contrastive_jit_flip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrastive_color) as usize - ptr as usize },
+        364usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(contrastive_color)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).unsupervised) as usize - ptr as usize },
+        368usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(unsupervised)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).angle) as usize - ptr as usize },
+        372usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(angle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).aspect) as usize - ptr as usize },
+        376usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(aspect)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).exposure) as usize - ptr as usize },
+        380usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(exposure)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).saturation) as usize - ptr as usize },
+        384usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(saturation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hue) as usize - ptr as usize },
+        388usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(hue)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).random) as usize - ptr as usize },
+        392usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(random)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).track) as usize - ptr as usize },
+        396usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(track)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).augment_speed) as usize - ptr as usize },
+        400usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(augment_speed)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sequential_subdivisions) as usize - ptr as usize },
+        404usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(sequential_subdivisions)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            ::std::ptr::addr_of!((*ptr).init_sequential_subdivisions) as usize - ptr as usize
+        },
+        408usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(init_sequential_subdivisions)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).current_subdivision) as usize - ptr as usize },
+        412usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(current_subdivision)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).try_fix_nan) as usize - ptr as usize },
+        416usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(try_fix_nan)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gpu_index) as usize - ptr as usize },
+        420usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(gpu_index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hierarchy) as usize - ptr as usize },
+        424usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(hierarchy)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input) as usize - ptr as usize },
+        432usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(input)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truth) as usize - ptr as usize },
+        440usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(truth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta) as usize - ptr as usize },
+        448usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).workspace) as usize - ptr as usize },
+        456usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(workspace)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).train) as usize - ptr as usize },
+        464usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(train)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).index) as usize - ptr as usize },
+        468usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cost) as usize - ptr as usize },
+        472usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(cost)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).clip) as usize - ptr as usize },
+        480usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(clip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta_gpu) as usize - ptr as usize },
+        488usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output_gpu) as usize - ptr as usize },
+        496usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(output_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_state_gpu) as usize - ptr as usize },
+        504usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(input_state_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_pinned_cpu) as usize - ptr as usize },
+        512usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(input_pinned_cpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_pinned_cpu_flag) as usize - ptr as usize },
+        520usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(input_pinned_cpu_flag)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input_gpu) as usize - ptr as usize },
+        528usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(input_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truth_gpu) as usize - ptr as usize },
+        536usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(truth_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input16_gpu) as usize - ptr as usize },
+        544usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(input16_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).output16_gpu) as usize - ptr as usize },
+        552usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(output16_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_input16_size) as usize - ptr as usize },
+        560usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(max_input16_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_output16_size) as usize - ptr as usize },
+        568usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(max_output16_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).wait_stream) as usize - ptr as usize },
+        576usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(wait_stream)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cuda_graph) as usize - ptr as usize },
+        584usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(cuda_graph)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cuda_graph_exec) as usize - ptr as usize },
+        592usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(cuda_graph_exec)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).use_cuda_graph) as usize - ptr as usize },
+        600usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(use_cuda_graph)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cuda_graph_ready) as usize - ptr as usize },
+        608usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(cuda_graph_ready)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).global_delta_gpu) as usize - ptr as usize },
+        616usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(global_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).state_delta_gpu) as usize - ptr as usize },
+        624usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(state_delta_gpu)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max_delta_gpu_size) as usize - ptr as usize },
+        632usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(max_delta_gpu_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).optimized_memory) as usize - ptr as usize },
+        640usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(optimized_memory)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dynamic_minibatch) as usize - ptr as usize },
+        644usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(dynamic_minibatch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).workspace_size_limit) as usize - ptr as usize },
+        648usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network),
+            "::",
+            stringify!(workspace_size_limit)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct network_state {
+    pub truth: *mut f32,
+    pub input: *mut f32,
+    pub delta: *mut f32,
+    pub workspace: *mut f32,
+    pub train: ::std::os::raw::c_int,
+    pub index: ::std::os::raw::c_int,
+    pub net: network,
+}
+#[test]
+fn bindgen_test_layout_network_state() {
+    const UNINIT: ::std::mem::MaybeUninit<network_state> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<network_state>(),
+        696usize,
+        concat!("Size of: ", stringify!(network_state))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<network_state>(),
+        8usize,
+        concat!("Alignment of ", stringify!(network_state))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truth) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network_state),
+            "::",
+            stringify!(truth)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).input) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network_state),
+            "::",
+            stringify!(input)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).delta) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network_state),
+            "::",
+            stringify!(delta)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).workspace) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network_state),
+            "::",
+            stringify!(workspace)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).train) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network_state),
+            "::",
+            stringify!(train)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).index) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network_state),
+            "::",
+            stringify!(index)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).net) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(network_state),
+            "::",
+            stringify!(net)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct image {
+    pub w: ::std::os::raw::c_int,
+    pub h: ::std::os::raw::c_int,
+    pub c: ::std::os::raw::c_int,
+    pub data: *mut f32,
+}
+#[test]
+fn bindgen_test_layout_image() {
+    const UNINIT: ::std::mem::MaybeUninit<image> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<image>(),
+        24usize,
+        concat!("Size of: ", stringify!(image))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<image>(),
+        8usize,
+        concat!("Alignment of ", stringify!(image))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(image), "::", stringify!(w))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        4usize,
+        concat!("Offset of field: ", stringify!(image), "::", stringify!(h))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).c) as usize - ptr as usize },
+        8usize,
+        concat!("Offset of field: ", stringify!(image), "::", stringify!(c))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(image),
+            "::",
+            stringify!(data)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct box_ {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+#[test]
+fn bindgen_test_layout_box_() {
+    const UNINIT: ::std::mem::MaybeUninit<box_> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<box_>(),
+        16usize,
+        concat!("Size of: ", stringify!(box_))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<box_>(),
+        4usize,
+        concat!("Alignment of ", stringify!(box_))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(box_), "::", stringify!(x))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        4usize,
+        concat!("Offset of field: ", stringify!(box_), "::", stringify!(y))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        8usize,
+        concat!("Offset of field: ", stringify!(box_), "::", stringify!(w))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        12usize,
+        concat!("Offset of field: ", stringify!(box_), "::", stringify!(h))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct boxabs {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bot: f32,
+}
+#[test]
+fn bindgen_test_layout_boxabs() {
+    const UNINIT: ::std::mem::MaybeUninit<boxabs> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<boxabs>(),
+        16usize,
+        concat!("Size of: ", stringify!(boxabs))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<boxabs>(),
+        4usize,
+        concat!("Alignment of ", stringify!(boxabs))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).left) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(boxabs),
+            "::",
+            stringify!(left)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).right) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(boxabs),
+            "::",
+            stringify!(right)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).top) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(boxabs),
+            "::",
+            stringify!(top)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bot) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(boxabs),
+            "::",
+            stringify!(bot)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct dxrep {
+    pub dt: f32,
+    pub db: f32,
+    pub dl: f32,
+    pub dr: f32,
+}
+#[test]
+fn bindgen_test_layout_dxrep() {
+    const UNINIT: ::std::mem::MaybeUninit<dxrep> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<dxrep>(),
+        16usize,
+        concat!("Size of: ", stringify!(dxrep))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<dxrep>(),
+        4usize,
+        concat!("Alignment of ", stringify!(dxrep))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dt) as usize - ptr as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(dxrep), "::", stringify!(dt))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).db) as usize - ptr as usize },
+        4usize,
+        concat!("Offset of field: ", stringify!(dxrep), "::", stringify!(db))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dl) as usize - ptr as usize },
+        8usize,
+        concat!("Offset of field: ", stringify!(dxrep), "::", stringify!(dl))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dr) as usize - ptr as usize },
+        12usize,
+        concat!("Offset of field: ", stringify!(dxrep), "::", stringify!(dr))
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ious {
+    pub iou: f32,
+    pub giou: f32,
+    pub diou: f32,
+    pub ciou: f32,
+    pub dx_iou: dxrep,
+    pub dx_giou: dxrep,
+}
+#[test]
+fn bindgen_test_layout_ious() {
+    const UNINIT: ::std::mem::MaybeUninit<ious> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<ious>(),
+        48usize,
+        concat!("Size of: ", stringify!(ious))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<ious>(),
+        4usize,
+        concat!("Alignment of ", stringify!(ious))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).iou) as usize - ptr as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(ious), "::", stringify!(iou))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).giou) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ious),
+            "::",
+            stringify!(giou)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).diou) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ious),
+            "::",
+            stringify!(diou)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).ciou) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ious),
+            "::",
+            stringify!(ciou)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dx_iou) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ious),
+            "::",
+            stringify!(dx_iou)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dx_giou) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(ious),
+            "::",
+            stringify!(dx_giou)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct detection {
+    pub bbox: box_,
+    pub classes: ::std::os::raw::c_int,
+    pub best_class_idx: ::std::os::raw::c_int,
+    pub prob: *mut f32,
+    pub mask: *mut f32,
+    pub objectness: f32,
+    pub sort_class: ::std::os::raw::c_int,
+    pub uc: *mut f32,
+    pub points: ::std::os::raw::c_int,
+    pub embeddings: *mut f32,
+    pub embedding_size: ::std::os::raw::c_int,
+    pub sim: f32,
+    pub track_id: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_detection() {
+    const UNINIT: ::std::mem::MaybeUninit<detection> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<detection>(),
+        88usize,
+        concat!("Size of: ", stringify!(detection))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<detection>(),
+        8usize,
+        concat!("Alignment of ", stringify!(detection))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bbox) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(bbox)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classes) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(classes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).best_class_idx) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(best_class_idx)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).prob) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(prob)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mask) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(mask)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).objectness) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(objectness)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sort_class) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(sort_class)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).uc) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(uc)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).points) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(points)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).embeddings) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(embeddings)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).embedding_size) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(embedding_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).sim) as usize - ptr as usize },
+        76usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(sim)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).track_id) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(detection),
+            "::",
+            stringify!(track_id)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct det_num_pair {
+    pub num: ::std::os::raw::c_int,
+    pub dets: *mut detection,
+}
+#[test]
+fn bindgen_test_layout_det_num_pair() {
+    const UNINIT: ::std::mem::MaybeUninit<det_num_pair> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<det_num_pair>(),
+        16usize,
+        concat!("Size of: ", stringify!(det_num_pair))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<det_num_pair>(),
+        8usize,
+        concat!("Alignment of ", stringify!(det_num_pair))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(det_num_pair),
+            "::",
+            stringify!(num)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dets) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(det_num_pair),
+            "::",
+            stringify!(dets)
+        )
+    );
+}
+pub type pdet_num_pair = *mut det_num_pair;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct matrix {
+    pub rows: ::std::os::raw::c_int,
+    pub cols: ::std::os::raw::c_int,
+    pub vals: *mut *mut f32,
+}
+#[test]
+fn bindgen_test_layout_matrix() {
+    const UNINIT: ::std::mem::MaybeUninit<matrix> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<matrix>(),
+        16usize,
+        concat!("Size of: ", stringify!(matrix))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<matrix>(),
+        8usize,
+        concat!("Alignment of ", stringify!(matrix))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).rows) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(matrix),
+            "::",
+            stringify!(rows)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cols) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(matrix),
+            "::",
+            stringify!(cols)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).vals) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(matrix),
+            "::",
+            stringify!(vals)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct data {
+    pub w: ::std::os::raw::c_int,
+    pub h: ::std::os::raw::c_int,
+    pub X: matrix,
+    pub y: matrix,
+    pub shallow: ::std::os::raw::c_int,
+    pub num_boxes: *mut ::std::os::raw::c_int,
+    pub boxes: *mut *mut box_,
+}
+#[test]
+fn bindgen_test_layout_data() {
+    const UNINIT: ::std::mem::MaybeUninit<data> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<data>(),
+        64usize,
+        concat!("Size of: ", stringify!(data))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<data>(),
+        8usize,
+        concat!("Alignment of ", stringify!(data))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(data), "::", stringify!(w))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        4usize,
+        concat!("Offset of field: ", stringify!(data), "::", stringify!(h))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).X) as usize - ptr as usize },
+        8usize,
+        concat!("Offset of field: ", stringify!(data), "::", stringify!(X))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        24usize,
+        concat!("Offset of field: ", stringify!(data), "::", stringify!(y))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).shallow) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(data),
+            "::",
+            stringify!(shallow)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num_boxes) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(data),
+            "::",
+            stringify!(num_boxes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).boxes) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(data),
+            "::",
+            stringify!(boxes)
+        )
+    );
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum data_type {
+    CLASSIFICATION_DATA = 0,
+    DETECTION_DATA = 1,
+    CAPTCHA_DATA = 2,
+    REGION_DATA = 3,
+    IMAGE_DATA = 4,
+    COMPARE_DATA = 5,
+    WRITING_DATA = 6,
+    SWAG_DATA = 7,
+    TAG_DATA = 8,
+    OLD_CLASSIFICATION_DATA = 9,
+    STUDY_DATA = 10,
+    DET_DATA = 11,
+    SUPER_DATA = 12,
+    LETTERBOX_DATA = 13,
+    REGRESSION_DATA = 14,
+    SEGMENTATION_DATA = 15,
+    INSTANCE_DATA = 16,
+    ISEG_DATA = 17,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct load_args {
+    pub threads: ::std::os::raw::c_int,
+    pub paths: *mut *mut ::std::os::raw::c_char,
+    pub path: *mut ::std::os::raw::c_char,
+    pub n: ::std::os::raw::c_int,
+    pub m: ::std::os::raw::c_int,
+    pub labels: *mut *mut ::std::os::raw::c_char,
+    pub h: ::std::os::raw::c_int,
+    pub w: ::std::os::raw::c_int,
+    pub c: ::std::os::raw::c_int,
+    pub out_w: ::std::os::raw::c_int,
+    pub out_h: ::std::os::raw::c_int,
+    pub nh: ::std::os::raw::c_int,
+    pub nw: ::std::os::raw::c_int,
+    pub num_boxes: ::std::os::raw::c_int,
+    pub truth_size: ::std::os::raw::c_int,
+    pub min: ::std::os::raw::c_int,
+    pub max: ::std::os::raw::c_int,
+    pub size: ::std::os::raw::c_int,
+    pub classes: ::std::os::raw::c_int,
+    pub background: ::std::os::raw::c_int,
+    pub scale: ::std::os::raw::c_int,
+    pub center: ::std::os::raw::c_int,
+    pub coords: ::std::os::raw::c_int,
+    pub mini_batch: ::std::os::raw::c_int,
+    pub track: ::std::os::raw::c_int,
+    pub augment_speed: ::std::os::raw::c_int,
+    pub letter_box: ::std::os::raw::c_int,
+    pub mosaic_bound: ::std::os::raw::c_int,
+    pub show_imgs: ::std::os::raw::c_int,
+    pub dontuse_opencv: ::std::os::raw::c_int,
+    pub contrastive: ::std::os::raw::c_int,
+    pub contrastive_jit_flip: ::std::os::raw::c_int,
+    pub contrastive_color: ::std::os::raw::c_int,
+    pub jitter: f32,
+    pub resize: f32,
+    pub flip: ::std::os::raw::c_int,
+    pub gaussian_noise: ::std::os::raw::c_int,
+    pub blur: ::std::os::raw::c_int,
+    pub mixup: ::std::os::raw::c_int,
+    pub label_smooth_eps: f32,
+    pub angle: f32,
+    pub aspect: f32,
+    pub saturation: f32,
+    pub exposure: f32,
+    pub hue: f32,
+    pub d: *mut data,
+    pub im: *mut image,
+    pub resized: *mut image,
+    pub type_: data_type,
+    pub hierarchy: *mut tree,
+}
+#[test]
+fn bindgen_test_layout_load_args() {
+    const UNINIT: ::std::mem::MaybeUninit<load_args> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<load_args>(),
+        240usize,
+        concat!("Size of: ", stringify!(load_args))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<load_args>(),
+        8usize,
+        concat!("Alignment of ", stringify!(load_args))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).threads) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(threads)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).paths) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(paths)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).path) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(path)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).n) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(n)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).m) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(m)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).labels) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(labels)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        44usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).c) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(c)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).out_w) as usize - ptr as usize },
+        52usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(out_w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).out_h) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(out_h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nh) as usize - ptr as usize },
+        60usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(nh)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).nw) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(nw)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).num_boxes) as usize - ptr as usize },
+        68usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(num_boxes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).truth_size) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(truth_size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).min) as usize - ptr as usize },
+        76usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(min)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).max) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(max)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).size) as usize - ptr as usize },
+        84usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(size)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).classes) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(classes)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).background) as usize - ptr as usize },
+        92usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(background)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).scale) as usize - ptr as usize },
+        96usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(scale)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).center) as usize - ptr as usize },
+        100usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(center)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).coords) as usize - ptr as usize },
+        104usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(coords)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mini_batch) as usize - ptr as usize },
+        108usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(mini_batch)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).track) as usize - ptr as usize },
+        112usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(track)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).augment_speed) as usize - ptr as usize },
+        116usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(augment_speed)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).letter_box) as usize - ptr as usize },
+        120usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(letter_box)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mosaic_bound) as usize - ptr as usize },
+        124usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(mosaic_bound)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).show_imgs) as usize - ptr as usize },
+        128usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(show_imgs)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).dontuse_opencv) as usize - ptr as usize },
+        132usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(dontuse_opencv)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrastive) as usize - ptr as usize },
+        136usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(contrastive)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrastive_jit_flip) as usize - ptr as usize },
+        140usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(contrastive_jit_flip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).contrastive_color) as usize - ptr as usize },
+        144usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(contrastive_color)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).jitter) as usize - ptr as usize },
+        148usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(jitter)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).resize) as usize - ptr as usize },
+        152usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(resize)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).flip) as usize - ptr as usize },
+        156usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(flip)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).gaussian_noise) as usize - ptr as usize },
+        160usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(gaussian_noise)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).blur) as usize - ptr as usize },
+        164usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(blur)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).mixup) as usize - ptr as usize },
+        168usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(mixup)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).label_smooth_eps) as usize - ptr as usize },
+        172usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(label_smooth_eps)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).angle) as usize - ptr as usize },
+        176usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(angle)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).aspect) as usize - ptr as usize },
+        180usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(aspect)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).saturation) as usize - ptr as usize },
+        184usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(saturation)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).exposure) as usize - ptr as usize },
+        188usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(exposure)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hue) as usize - ptr as usize },
+        192usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(hue)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).d) as usize - ptr as usize },
+        200usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(d)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).im) as usize - ptr as usize },
+        208usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(im)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).resized) as usize - ptr as usize },
+        216usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(resized)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+        224usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(type_)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).hierarchy) as usize - ptr as usize },
+        232usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(load_args),
+            "::",
+            stringify!(hierarchy)
+        )
+    );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct box_label {
+    pub id: ::std::os::raw::c_int,
+    pub track_id: ::std::os::raw::c_int,
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+#[test]
+fn bindgen_test_layout_box_label() {
+    const UNINIT: ::std::mem::MaybeUninit<box_label> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<box_label>(),
+        40usize,
+        concat!("Size of: ", stringify!(box_label))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<box_label>(),
+        4usize,
+        concat!("Alignment of ", stringify!(box_label))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).id) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).track_id) as usize - ptr as usize },
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(track_id)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(x)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+        12usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(y)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).w) as usize - ptr as usize },
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(w)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).h) as usize - ptr as usize },
+        20usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(h)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).left) as usize - ptr as usize },
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(left)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).right) as usize - ptr as usize },
+        28usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(right)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).top) as usize - ptr as usize },
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(top)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).bottom) as usize - ptr as usize },
+        36usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(box_label),
+            "::",
+            stringify!(bottom)
+        )
+    );
+}
+pub struct Darknet {
+    __library: ::libloading::Library,
+    pub load_network: unsafe extern "C" fn(
+        cfg: *mut ::std::os::raw::c_char,
+        weights: *mut ::std::os::raw::c_char,
+        clear: ::std::os::raw::c_int,
+    ) -> *mut network,
+    pub load_network_custom: unsafe extern "C" fn(
+        cfg: *mut ::std::os::raw::c_char,
+        weights: *mut ::std::os::raw::c_char,
+        clear: ::std::os::raw::c_int,
+        batch: ::std::os::raw::c_int,
+    ) -> *mut network,
+    pub free_network: unsafe extern "C" fn(net: network),
+    pub free_network_ptr: unsafe extern "C" fn(net: *mut network),
+    pub get_base_args: unsafe extern "C" fn(net: *mut network) -> load_args,
+    pub do_nms_sort: unsafe extern "C" fn(
+        dets: *mut detection,
+        total: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        thresh: f32,
+    ),
+    pub do_nms_obj: unsafe extern "C" fn(
+        dets: *mut detection,
+        total: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        thresh: f32,
+    ),
+    pub diounms_sort: unsafe extern "C" fn(
+        dets: *mut detection,
+        total: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        thresh: f32,
+        nms_kind: NMS_KIND,
+        beta1: f32,
+    ),
+    pub network_predict: unsafe extern "C" fn(net: network, input: *mut f32) -> *mut f32,
+    pub network_predict_ptr: unsafe extern "C" fn(net: *mut network, input: *mut f32) -> *mut f32,
+    pub get_network_boxes: unsafe extern "C" fn(
+        net: *mut network,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        thresh: f32,
+        hier: f32,
+        map: *mut ::std::os::raw::c_int,
+        relative: ::std::os::raw::c_int,
+        num: *mut ::std::os::raw::c_int,
+        letter: ::std::os::raw::c_int,
+    ) -> *mut detection,
+    pub network_predict_batch: unsafe extern "C" fn(
+        net: *mut network,
+        im: image,
+        batch_size: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        thresh: f32,
+        hier: f32,
+        map: *mut ::std::os::raw::c_int,
+        relative: ::std::os::raw::c_int,
+        letter: ::std::os::raw::c_int,
+    ) -> *mut det_num_pair,
+    pub free_detections: unsafe extern "C" fn(dets: *mut detection, n: ::std::os::raw::c_int),
+    pub free_batch_detections:
+        unsafe extern "C" fn(det_num_pairs: *mut det_num_pair, n: ::std::os::raw::c_int),
+    pub fuse_conv_batchnorm: unsafe extern "C" fn(net: network),
+    pub calculate_binary_weights: unsafe extern "C" fn(net: network),
+    pub detection_to_json: unsafe extern "C" fn(
+        dets: *mut detection,
+        nboxes: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        names: *mut *mut ::std::os::raw::c_char,
+        frame_id: ::std::os::raw::c_longlong,
+        filename: *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char,
+    pub get_network_layer:
+        unsafe extern "C" fn(net: *mut network, i: ::std::os::raw::c_int) -> *mut layer,
+    pub make_network_boxes: unsafe extern "C" fn(
+        net: *mut network,
+        thresh: f32,
+        num: *mut ::std::os::raw::c_int,
+    ) -> *mut detection,
+    pub reset_rnn: unsafe extern "C" fn(net: *mut network),
+    pub network_predict_image: unsafe extern "C" fn(net: *mut network, im: image) -> *mut f32,
+    pub network_predict_image_letterbox:
+        unsafe extern "C" fn(net: *mut network, im: image) -> *mut f32,
+    pub validate_detector_map: unsafe extern "C" fn(
+        datacfg: *mut ::std::os::raw::c_char,
+        cfgfile: *mut ::std::os::raw::c_char,
+        weightfile: *mut ::std::os::raw::c_char,
+        thresh_calc_avg_iou: f32,
+        iou_thresh: f32,
+        map_points: ::std::os::raw::c_int,
+        letter_box: ::std::os::raw::c_int,
+        existing_net: *mut network,
+    ) -> f32,
+    pub train_detector: unsafe extern "C" fn(
+        datacfg: *mut ::std::os::raw::c_char,
+        cfgfile: *mut ::std::os::raw::c_char,
+        weightfile: *mut ::std::os::raw::c_char,
+        gpus: *mut ::std::os::raw::c_int,
+        ngpus: ::std::os::raw::c_int,
+        clear: ::std::os::raw::c_int,
+        dont_show: ::std::os::raw::c_int,
+        calc_map: ::std::os::raw::c_int,
+        thresh: f32,
+        iou_thresh: f32,
+        mjpeg_port: ::std::os::raw::c_int,
+        show_imgs: ::std::os::raw::c_int,
+        benchmark_layers: ::std::os::raw::c_int,
+        chart_path: *mut ::std::os::raw::c_char,
+    ),
+    pub test_detector: unsafe extern "C" fn(
+        datacfg: *mut ::std::os::raw::c_char,
+        cfgfile: *mut ::std::os::raw::c_char,
+        weightfile: *mut ::std::os::raw::c_char,
+        filename: *mut ::std::os::raw::c_char,
+        thresh: f32,
+        hier_thresh: f32,
+        dont_show: ::std::os::raw::c_int,
+        ext_output: ::std::os::raw::c_int,
+        save_labels: ::std::os::raw::c_int,
+        outfile: *mut ::std::os::raw::c_char,
+        letter_box: ::std::os::raw::c_int,
+        benchmark_layers: ::std::os::raw::c_int,
+    ),
+    pub network_width: unsafe extern "C" fn(net: *mut network) -> ::std::os::raw::c_int,
+    pub network_height: unsafe extern "C" fn(net: *mut network) -> ::std::os::raw::c_int,
+    pub optimize_picture: unsafe extern "C" fn(
+        net: *mut network,
+        orig: image,
+        max_layer: ::std::os::raw::c_int,
+        scale: f32,
+        rate: f32,
+        thresh: f32,
+        norm: ::std::os::raw::c_int,
+    ),
+    pub make_image_red: unsafe extern "C" fn(im: image),
+    pub make_attention_image: unsafe extern "C" fn(
+        img_size: ::std::os::raw::c_int,
+        original_delta_cpu: *mut f32,
+        original_input_cpu: *mut f32,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+        alpha: f32,
+    ) -> image,
+    pub resize_image: unsafe extern "C" fn(
+        im: image,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image,
+    pub quantize_image: unsafe extern "C" fn(im: image),
+    pub copy_image_from_bytes: unsafe extern "C" fn(im: image, pdata: *mut ::std::os::raw::c_char),
+    pub letterbox_image: unsafe extern "C" fn(
+        im: image,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image,
+    pub rgbgr_image: unsafe extern "C" fn(im: image),
+    pub make_image: unsafe extern "C" fn(
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+    ) -> image,
+    pub load_image_color: unsafe extern "C" fn(
+        filename: *mut ::std::os::raw::c_char,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image,
+    pub free_image: unsafe extern "C" fn(m: image),
+    pub crop_image: unsafe extern "C" fn(
+        im: image,
+        dx: ::std::os::raw::c_int,
+        dy: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image,
+    pub resize_min: unsafe extern "C" fn(im: image, min: ::std::os::raw::c_int) -> image,
+    pub free_layer_custom: unsafe extern "C" fn(l: layer, keep_cudnn_desc: ::std::os::raw::c_int),
+    pub free_layer: unsafe extern "C" fn(l: layer),
+    pub free_data: unsafe extern "C" fn(d: data),
+    pub load_data: unsafe extern "C" fn(args: load_args) -> pthread_t,
+    pub free_load_threads: unsafe extern "C" fn(ptr: *mut ::std::os::raw::c_void),
+    pub load_data_in_thread: unsafe extern "C" fn(args: load_args) -> pthread_t,
+    pub load_thread:
+        unsafe extern "C" fn(ptr: *mut ::std::os::raw::c_void) -> *mut ::std::os::raw::c_void,
+    pub cuda_set_device: unsafe extern "C" fn(n: ::std::os::raw::c_int),
+    pub free_ptrs:
+        unsafe extern "C" fn(ptrs: *mut *mut ::std::os::raw::c_void, n: ::std::os::raw::c_int),
+    pub top_k: unsafe extern "C" fn(
+        a: *mut f32,
+        n: ::std::os::raw::c_int,
+        k: ::std::os::raw::c_int,
+        index: *mut ::std::os::raw::c_int,
+    ),
+    pub read_tree: unsafe extern "C" fn(filename: *mut ::std::os::raw::c_char) -> *mut tree,
+    pub get_metadata: unsafe extern "C" fn(file: *mut ::std::os::raw::c_char) -> metadata,
+    pub delete_json_sender: unsafe extern "C" fn(),
+    pub send_json_custom: unsafe extern "C" fn(
+        send_buf: *const ::std::os::raw::c_char,
+        port: ::std::os::raw::c_int,
+        timeout: ::std::os::raw::c_int,
+    ),
+    pub get_time_point: unsafe extern "C" fn() -> f64,
+    pub start_timer: unsafe extern "C" fn(),
+    pub stop_timer: unsafe extern "C" fn(),
+    pub get_time: unsafe extern "C" fn() -> f64,
+    pub stop_timer_and_show: unsafe extern "C" fn(),
+    pub stop_timer_and_show_name: unsafe extern "C" fn(name: *mut ::std::os::raw::c_char),
+    pub show_total_time: unsafe extern "C" fn(),
+    pub set_track_id: unsafe extern "C" fn(
+        new_dets: *mut detection,
+        new_dets_num: ::std::os::raw::c_int,
+        thresh: f32,
+        sim_thresh: f32,
+        track_ciou_norm: f32,
+        deque_size: ::std::os::raw::c_int,
+        dets_for_track: ::std::os::raw::c_int,
+        dets_for_show: ::std::os::raw::c_int,
+    ),
+    pub fill_remaining_id: unsafe extern "C" fn(
+        new_dets: *mut detection,
+        new_dets_num: ::std::os::raw::c_int,
+        new_track_id: ::std::os::raw::c_int,
+        thresh: f32,
+    ) -> ::std::os::raw::c_int,
+    pub init_cpu: unsafe extern "C" fn(),
+}
+impl Darknet {
+    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
+    where
+        P: AsRef<::std::ffi::OsStr>,
+    {
+        let library = ::libloading::Library::new(path)?;
+        Self::from_library(library)
+    }
+    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>
+    where
+        L: Into<::libloading::Library>,
+    {
+        let __library = library.into();
+        let load_network = __library.get(b"load_network\0").map(|sym| *sym)?;
+        let load_network_custom = __library.get(b"load_network_custom\0").map(|sym| *sym)?;
+        let free_network = __library.get(b"free_network\0").map(|sym| *sym)?;
+        let free_network_ptr = __library.get(b"free_network_ptr\0").map(|sym| *sym)?;
+        let get_base_args = __library.get(b"get_base_args\0").map(|sym| *sym)?;
+        let do_nms_sort = __library.get(b"do_nms_sort\0").map(|sym| *sym)?;
+        let do_nms_obj = __library.get(b"do_nms_obj\0").map(|sym| *sym)?;
+        let diounms_sort = __library.get(b"diounms_sort\0").map(|sym| *sym)?;
+        let network_predict = __library.get(b"network_predict\0").map(|sym| *sym)?;
+        let network_predict_ptr = __library.get(b"network_predict_ptr\0").map(|sym| *sym)?;
+        let get_network_boxes = __library.get(b"get_network_boxes\0").map(|sym| *sym)?;
+        let network_predict_batch = __library.get(b"network_predict_batch\0").map(|sym| *sym)?;
+        let free_detections = __library.get(b"free_detections\0").map(|sym| *sym)?;
+        let free_batch_detections = __library.get(b"free_batch_detections\0").map(|sym| *sym)?;
+        let fuse_conv_batchnorm = __library.get(b"fuse_conv_batchnorm\0").map(|sym| *sym)?;
+        let calculate_binary_weights = __library
+            .get(b"calculate_binary_weights\0")
+            .map(|sym| *sym)?;
+        let detection_to_json = __library.get(b"detection_to_json\0").map(|sym| *sym)?;
+        let get_network_layer = __library.get(b"get_network_layer\0").map(|sym| *sym)?;
+        let make_network_boxes = __library.get(b"make_network_boxes\0").map(|sym| *sym)?;
+        let reset_rnn = __library.get(b"reset_rnn\0").map(|sym| *sym)?;
+        let network_predict_image = __library.get(b"network_predict_image\0").map(|sym| *sym)?;
+        let network_predict_image_letterbox = __library
+            .get(b"network_predict_image_letterbox\0")
+            .map(|sym| *sym)?;
+        let validate_detector_map = __library.get(b"validate_detector_map\0").map(|sym| *sym)?;
+        let train_detector = __library.get(b"train_detector\0").map(|sym| *sym)?;
+        let test_detector = __library.get(b"test_detector\0").map(|sym| *sym)?;
+        let network_width = __library.get(b"network_width\0").map(|sym| *sym)?;
+        let network_height = __library.get(b"network_height\0").map(|sym| *sym)?;
+        let optimize_picture = __library.get(b"optimize_picture\0").map(|sym| *sym)?;
+        let make_image_red = __library.get(b"make_image_red\0").map(|sym| *sym)?;
+        let make_attention_image = __library.get(b"make_attention_image\0").map(|sym| *sym)?;
+        let resize_image = __library.get(b"resize_image\0").map(|sym| *sym)?;
+        let quantize_image = __library.get(b"quantize_image\0").map(|sym| *sym)?;
+        let copy_image_from_bytes = __library.get(b"copy_image_from_bytes\0").map(|sym| *sym)?;
+        let letterbox_image = __library.get(b"letterbox_image\0").map(|sym| *sym)?;
+        let rgbgr_image = __library.get(b"rgbgr_image\0").map(|sym| *sym)?;
+        let make_image = __library.get(b"make_image\0").map(|sym| *sym)?;
+        let load_image_color = __library.get(b"load_image_color\0").map(|sym| *sym)?;
+        let free_image = __library.get(b"free_image\0").map(|sym| *sym)?;
+        let crop_image = __library.get(b"crop_image\0").map(|sym| *sym)?;
+        let resize_min = __library.get(b"resize_min\0").map(|sym| *sym)?;
+        let free_layer_custom = __library.get(b"free_layer_custom\0").map(|sym| *sym)?;
+        let free_layer = __library.get(b"free_layer\0").map(|sym| *sym)?;
+        let free_data = __library.get(b"free_data\0").map(|sym| *sym)?;
+        let load_data = __library.get(b"load_data\0").map(|sym| *sym)?;
+        let free_load_threads = __library.get(b"free_load_threads\0").map(|sym| *sym)?;
+        let load_data_in_thread = __library.get(b"load_data_in_thread\0").map(|sym| *sym)?;
+        let load_thread = __library.get(b"load_thread\0").map(|sym| *sym)?;
+        let cuda_set_device = __library.get(b"cuda_set_device\0").map(|sym| *sym)?;
+        let free_ptrs = __library.get(b"free_ptrs\0").map(|sym| *sym)?;
+        let top_k = __library.get(b"top_k\0").map(|sym| *sym)?;
+        let read_tree = __library.get(b"read_tree\0").map(|sym| *sym)?;
+        let get_metadata = __library.get(b"get_metadata\0").map(|sym| *sym)?;
+        let delete_json_sender = __library.get(b"delete_json_sender\0").map(|sym| *sym)?;
+        let send_json_custom = __library.get(b"send_json_custom\0").map(|sym| *sym)?;
+        let get_time_point = __library.get(b"get_time_point\0").map(|sym| *sym)?;
+        let start_timer = __library.get(b"start_timer\0").map(|sym| *sym)?;
+        let stop_timer = __library.get(b"stop_timer\0").map(|sym| *sym)?;
+        let get_time = __library.get(b"get_time\0").map(|sym| *sym)?;
+        let stop_timer_and_show = __library.get(b"stop_timer_and_show\0").map(|sym| *sym)?;
+        let stop_timer_and_show_name = __library
+            .get(b"stop_timer_and_show_name\0")
+            .map(|sym| *sym)?;
+        let show_total_time = __library.get(b"show_total_time\0").map(|sym| *sym)?;
+        let set_track_id = __library.get(b"set_track_id\0").map(|sym| *sym)?;
+        let fill_remaining_id = __library.get(b"fill_remaining_id\0").map(|sym| *sym)?;
+        let init_cpu = __library.get(b"init_cpu\0").map(|sym| *sym)?;
+        Ok(Darknet {
+            __library,
+            load_network,
+            load_network_custom,
+            free_network,
+            free_network_ptr,
+            get_base_args,
+            do_nms_sort,
+            do_nms_obj,
+            diounms_sort,
+            network_predict,
+            network_predict_ptr,
+            get_network_boxes,
+            network_predict_batch,
+            free_detections,
+            free_batch_detections,
+            fuse_conv_batchnorm,
+            calculate_binary_weights,
+            detection_to_json,
+            get_network_layer,
+            make_network_boxes,
+            reset_rnn,
+            network_predict_image,
+            network_predict_image_letterbox,
+            validate_detector_map,
+            train_detector,
+            test_detector,
+            network_width,
+            network_height,
+            optimize_picture,
+            make_image_red,
+            make_attention_image,
+            resize_image,
+            quantize_image,
+            copy_image_from_bytes,
+            letterbox_image,
+            rgbgr_image,
+            make_image,
+            load_image_color,
+            free_image,
+            crop_image,
+            resize_min,
+            free_layer_custom,
+            free_layer,
+            free_data,
+            load_data,
+            free_load_threads,
+            load_data_in_thread,
+            load_thread,
+            cuda_set_device,
+            free_ptrs,
+            top_k,
+            read_tree,
+            get_metadata,
+            delete_json_sender,
+            send_json_custom,
+            get_time_point,
+            start_timer,
+            stop_timer,
+            get_time,
+            stop_timer_and_show,
+            stop_timer_and_show_name,
+            show_total_time,
+            set_track_id,
+            fill_remaining_id,
+            init_cpu,
+        })
+    }
+    pub unsafe fn load_network(
+        &self,
+        cfg: *mut ::std::os::raw::c_char,
+        weights: *mut ::std::os::raw::c_char,
+        clear: ::std::os::raw::c_int,
+    ) -> *mut network {
+        (self.load_network)(cfg, weights, clear)
+    }
+    pub unsafe fn load_network_custom(
+        &self,
+        cfg: *mut ::std::os::raw::c_char,
+        weights: *mut ::std::os::raw::c_char,
+        clear: ::std::os::raw::c_int,
+        batch: ::std::os::raw::c_int,
+    ) -> *mut network {
+        (self.load_network_custom)(cfg, weights, clear, batch)
+    }
+    pub unsafe fn free_network(&self, net: network) {
+        (self.free_network)(net)
+    }
+    pub unsafe fn free_network_ptr(&self, net: *mut network) {
+        (self.free_network_ptr)(net)
+    }
+    pub unsafe fn get_base_args(&self, net: *mut network) -> load_args {
+        (self.get_base_args)(net)
+    }
+    pub unsafe fn do_nms_sort(
+        &self,
+        dets: *mut detection,
+        total: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        thresh: f32,
+    ) {
+        (self.do_nms_sort)(dets, total, classes, thresh)
+    }
+    pub unsafe fn do_nms_obj(
+        &self,
+        dets: *mut detection,
+        total: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        thresh: f32,
+    ) {
+        (self.do_nms_obj)(dets, total, classes, thresh)
+    }
+    pub unsafe fn diounms_sort(
+        &self,
+        dets: *mut detection,
+        total: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        thresh: f32,
+        nms_kind: NMS_KIND,
+        beta1: f32,
+    ) {
+        (self.diounms_sort)(dets, total, classes, thresh, nms_kind, beta1)
+    }
+    pub unsafe fn network_predict(&self, net: network, input: *mut f32) -> *mut f32 {
+        (self.network_predict)(net, input)
+    }
+    pub unsafe fn network_predict_ptr(&self, net: *mut network, input: *mut f32) -> *mut f32 {
+        (self.network_predict_ptr)(net, input)
+    }
+    pub unsafe fn get_network_boxes(
+        &self,
+        net: *mut network,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        thresh: f32,
+        hier: f32,
+        map: *mut ::std::os::raw::c_int,
+        relative: ::std::os::raw::c_int,
+        num: *mut ::std::os::raw::c_int,
+        letter: ::std::os::raw::c_int,
+    ) -> *mut detection {
+        (self.get_network_boxes)(net, w, h, thresh, hier, map, relative, num, letter)
+    }
+    pub unsafe fn network_predict_batch(
+        &self,
+        net: *mut network,
+        im: image,
+        batch_size: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        thresh: f32,
+        hier: f32,
+        map: *mut ::std::os::raw::c_int,
+        relative: ::std::os::raw::c_int,
+        letter: ::std::os::raw::c_int,
+    ) -> *mut det_num_pair {
+        (self.network_predict_batch)(
+            net, im, batch_size, w, h, thresh, hier, map, relative, letter,
+        )
+    }
+    pub unsafe fn free_detections(&self, dets: *mut detection, n: ::std::os::raw::c_int) {
+        (self.free_detections)(dets, n)
+    }
+    pub unsafe fn free_batch_detections(
+        &self,
+        det_num_pairs: *mut det_num_pair,
+        n: ::std::os::raw::c_int,
+    ) {
+        (self.free_batch_detections)(det_num_pairs, n)
+    }
+    pub unsafe fn fuse_conv_batchnorm(&self, net: network) {
+        (self.fuse_conv_batchnorm)(net)
+    }
+    pub unsafe fn calculate_binary_weights(&self, net: network) {
+        (self.calculate_binary_weights)(net)
+    }
+    pub unsafe fn detection_to_json(
+        &self,
+        dets: *mut detection,
+        nboxes: ::std::os::raw::c_int,
+        classes: ::std::os::raw::c_int,
+        names: *mut *mut ::std::os::raw::c_char,
+        frame_id: ::std::os::raw::c_longlong,
+        filename: *mut ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_char {
+        (self.detection_to_json)(dets, nboxes, classes, names, frame_id, filename)
+    }
+    pub unsafe fn get_network_layer(
+        &self,
+        net: *mut network,
+        i: ::std::os::raw::c_int,
+    ) -> *mut layer {
+        (self.get_network_layer)(net, i)
+    }
+    pub unsafe fn make_network_boxes(
+        &self,
+        net: *mut network,
+        thresh: f32,
+        num: *mut ::std::os::raw::c_int,
+    ) -> *mut detection {
+        (self.make_network_boxes)(net, thresh, num)
+    }
+    pub unsafe fn reset_rnn(&self, net: *mut network) {
+        (self.reset_rnn)(net)
+    }
+    pub unsafe fn network_predict_image(&self, net: *mut network, im: image) -> *mut f32 {
+        (self.network_predict_image)(net, im)
+    }
+    pub unsafe fn network_predict_image_letterbox(&self, net: *mut network, im: image) -> *mut f32 {
+        (self.network_predict_image_letterbox)(net, im)
+    }
+    pub unsafe fn validate_detector_map(
+        &self,
+        datacfg: *mut ::std::os::raw::c_char,
+        cfgfile: *mut ::std::os::raw::c_char,
+        weightfile: *mut ::std::os::raw::c_char,
+        thresh_calc_avg_iou: f32,
+        iou_thresh: f32,
+        map_points: ::std::os::raw::c_int,
+        letter_box: ::std::os::raw::c_int,
+        existing_net: *mut network,
+    ) -> f32 {
+        (self.validate_detector_map)(
+            datacfg,
+            cfgfile,
+            weightfile,
+            thresh_calc_avg_iou,
+            iou_thresh,
+            map_points,
+            letter_box,
+            existing_net,
+        )
+    }
+    pub unsafe fn train_detector(
+        &self,
+        datacfg: *mut ::std::os::raw::c_char,
+        cfgfile: *mut ::std::os::raw::c_char,
+        weightfile: *mut ::std::os::raw::c_char,
+        gpus: *mut ::std::os::raw::c_int,
+        ngpus: ::std::os::raw::c_int,
+        clear: ::std::os::raw::c_int,
+        dont_show: ::std::os::raw::c_int,
+        calc_map: ::std::os::raw::c_int,
+        thresh: f32,
+        iou_thresh: f32,
+        mjpeg_port: ::std::os::raw::c_int,
+        show_imgs: ::std::os::raw::c_int,
+        benchmark_layers: ::std::os::raw::c_int,
+        chart_path: *mut ::std::os::raw::c_char,
+    ) {
+        (self.train_detector)(
+            datacfg,
+            cfgfile,
+            weightfile,
+            gpus,
+            ngpus,
+            clear,
+            dont_show,
+            calc_map,
+            thresh,
+            iou_thresh,
+            mjpeg_port,
+            show_imgs,
+            benchmark_layers,
+            chart_path,
+        )
+    }
+    pub unsafe fn test_detector(
+        &self,
+        datacfg: *mut ::std::os::raw::c_char,
+        cfgfile: *mut ::std::os::raw::c_char,
+        weightfile: *mut ::std::os::raw::c_char,
+        filename: *mut ::std::os::raw::c_char,
+        thresh: f32,
+        hier_thresh: f32,
+        dont_show: ::std::os::raw::c_int,
+        ext_output: ::std::os::raw::c_int,
+        save_labels: ::std::os::raw::c_int,
+        outfile: *mut ::std::os::raw::c_char,
+        letter_box: ::std::os::raw::c_int,
+        benchmark_layers: ::std::os::raw::c_int,
+    ) {
+        (self.test_detector)(
+            datacfg,
+            cfgfile,
+            weightfile,
+            filename,
+            thresh,
+            hier_thresh,
+            dont_show,
+            ext_output,
+            save_labels,
+            outfile,
+            letter_box,
+            benchmark_layers,
+        )
+    }
+    pub unsafe fn network_width(&self, net: *mut network) -> ::std::os::raw::c_int {
+        (self.network_width)(net)
+    }
+    pub unsafe fn network_height(&self, net: *mut network) -> ::std::os::raw::c_int {
+        (self.network_height)(net)
+    }
+    pub unsafe fn optimize_picture(
+        &self,
+        net: *mut network,
+        orig: image,
+        max_layer: ::std::os::raw::c_int,
+        scale: f32,
+        rate: f32,
+        thresh: f32,
+        norm: ::std::os::raw::c_int,
+    ) {
+        (self.optimize_picture)(net, orig, max_layer, scale, rate, thresh, norm)
+    }
+    pub unsafe fn make_image_red(&self, im: image) {
+        (self.make_image_red)(im)
+    }
+    pub unsafe fn make_attention_image(
+        &self,
+        img_size: ::std::os::raw::c_int,
+        original_delta_cpu: *mut f32,
+        original_input_cpu: *mut f32,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+        alpha: f32,
+    ) -> image {
+        (self.make_attention_image)(
+            img_size,
+            original_delta_cpu,
+            original_input_cpu,
+            w,
+            h,
+            c,
+            alpha,
+        )
+    }
+    pub unsafe fn resize_image(
+        &self,
+        im: image,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image {
+        (self.resize_image)(im, w, h)
+    }
+    pub unsafe fn quantize_image(&self, im: image) {
+        (self.quantize_image)(im)
+    }
+    pub unsafe fn copy_image_from_bytes(&self, im: image, pdata: *mut ::std::os::raw::c_char) {
+        (self.copy_image_from_bytes)(im, pdata)
+    }
+    pub unsafe fn letterbox_image(
+        &self,
+        im: image,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image {
+        (self.letterbox_image)(im, w, h)
+    }
+    pub unsafe fn rgbgr_image(&self, im: image) {
+        (self.rgbgr_image)(im)
+    }
+    pub unsafe fn make_image(
+        &self,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+        c: ::std::os::raw::c_int,
+    ) -> image {
+        (self.make_image)(w, h, c)
+    }
+    pub unsafe fn load_image_color(
+        &self,
+        filename: *mut ::std::os::raw::c_char,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image {
+        (self.load_image_color)(filename, w, h)
+    }
+    pub unsafe fn free_image(&self, m: image) {
+        (self.free_image)(m)
+    }
+    pub unsafe fn crop_image(
+        &self,
+        im: image,
+        dx: ::std::os::raw::c_int,
+        dy: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        h: ::std::os::raw::c_int,
+    ) -> image {
+        (self.crop_image)(im, dx, dy, w, h)
+    }
+    pub unsafe fn resize_min(&self, im: image, min: ::std::os::raw::c_int) -> image {
+        (self.resize_min)(im, min)
+    }
+    pub unsafe fn free_layer_custom(&self, l: layer, keep_cudnn_desc: ::std::os::raw::c_int) {
+        (self.free_layer_custom)(l, keep_cudnn_desc)
+    }
+    pub unsafe fn free_layer(&self, l: layer) {
+        (self.free_layer)(l)
+    }
+    pub unsafe fn free_data(&self, d: data) {
+        (self.free_data)(d)
+    }
+    pub unsafe fn load_data(&self, args: load_args) -> pthread_t {
+        (self.load_data)(args)
+    }
+    pub unsafe fn free_load_threads(&self, ptr: *mut ::std::os::raw::c_void) {
+        (self.free_load_threads)(ptr)
+    }
+    pub unsafe fn load_data_in_thread(&self, args: load_args) -> pthread_t {
+        (self.load_data_in_thread)(args)
+    }
+    pub unsafe fn load_thread(
+        &self,
+        ptr: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void {
+        (self.load_thread)(ptr)
+    }
+    pub unsafe fn cuda_set_device(&self, n: ::std::os::raw::c_int) {
+        (self.cuda_set_device)(n)
+    }
+    pub unsafe fn free_ptrs(
+        &self,
+        ptrs: *mut *mut ::std::os::raw::c_void,
+        n: ::std::os::raw::c_int,
+    ) {
+        (self.free_ptrs)(ptrs, n)
+    }
+    pub unsafe fn top_k(
+        &self,
+        a: *mut f32,
+        n: ::std::os::raw::c_int,
+        k: ::std::os::raw::c_int,
+        index: *mut ::std::os::raw::c_int,
+    ) {
+        (self.top_k)(a, n, k, index)
+    }
+    pub unsafe fn read_tree(&self, filename: *mut ::std::os::raw::c_char) -> *mut tree {
+        (self.read_tree)(filename)
+    }
+    pub unsafe fn get_metadata(&self, file: *mut ::std::os::raw::c_char) -> metadata {
+        (self.get_metadata)(file)
+    }
+    pub unsafe fn delete_json_sender(&self) {
+        (self.delete_json_sender)()
+    }
+    pub unsafe fn send_json_custom(
+        &self,
+        send_buf: *const ::std::os::raw::c_char,
+        port: ::std::os::raw::c_int,
+        timeout: ::std::os::raw::c_int,
+    ) {
+        (self.send_json_custom)(send_buf, port, timeout)
+    }
+    pub unsafe fn get_time_point(&self) -> f64 {
+        (self.get_time_point)()
+    }
+    pub unsafe fn start_timer(&self) {
+        (self.start_timer)()
+    }
+    pub unsafe fn stop_timer(&self) {
+        (self.stop_timer)()
+    }
+    pub unsafe fn get_time(&self) -> f64 {
+        (self.get_time)()
+    }
+    pub unsafe fn stop_timer_and_show(&self) {
+        (self.stop_timer_and_show)()
+    }
+    pub unsafe fn stop_timer_and_show_name(&self, name: *mut ::std::os::raw::c_char) {
+        (self.stop_timer_and_show_name)(name)
+    }
+    pub unsafe fn show_total_time(&self) {
+        (self.show_total_time)()
+    }
+    pub unsafe fn set_track_id(
+        &self,
+        new_dets: *mut detection,
+        new_dets_num: ::std::os::raw::c_int,
+        thresh: f32,
+        sim_thresh: f32,
+        track_ciou_norm: f32,
+        deque_size: ::std::os::raw::c_int,
+        dets_for_track: ::std::os::raw::c_int,
+        dets_for_show: ::std::os::raw::c_int,
+    ) {
+        (self.set_track_id)(
+            new_dets,
+            new_dets_num,
+            thresh,
+            sim_thresh,
+            track_ciou_norm,
+            deque_size,
+            dets_for_track,
+            dets_for_show,
+        )
+    }
+    pub unsafe fn fill_remaining_id(
+        &self,
+        new_dets: *mut detection,
+        new_dets_num: ::std::os::raw::c_int,
+        new_track_id: ::std::os::raw::c_int,
+        thresh: f32,
+    ) -> ::std::os::raw::c_int {
+        (self.fill_remaining_id)(new_dets, new_dets_num, new_track_id, thresh)
+    }
+    pub unsafe fn init_cpu(&self) {
+        (self.init_cpu)()
+    }
+}
+pub const DARKNET_SYMBOLS: &[&str] = &[
+    "load_network",
+    "load_network_custom",
+    "free_network",
+    "free_network_ptr",
+    "get_base_args",
+    "do_nms_sort",
+    "do_nms_obj",
+    "diounms_sort",
+    "network_predict",
+    "network_predict_ptr",
+    "get_network_boxes",
+    "network_predict_batch",
+    "free_detections",
+    "free_batch_detections",
+    "fuse_conv_batchnorm",
+    "calculate_binary_weights",
+    "detection_to_json",
+    "get_network_layer",
+    "make_network_boxes",
+    "reset_rnn",
+    "network_predict_image",
+    "network_predict_image_letterbox",
+    "validate_detector_map",
+    "train_detector",
+    "test_detector",
+    "network_width",
+    "network_height",
+    "optimize_picture",
+    "make_image_red",
+    "make_attention_image",
+    "resize_image",
+    "quantize_image",
+    "copy_image_from_bytes",
+    "letterbox_image",
+    "rgbgr_image",
+    "make_image",
+    "load_image_color",
+    "free_image",
+    "crop_image",
+    "resize_min",
+    "free_layer_custom",
+    "free_layer",
+    "free_data",
+    "load_data",
+    "free_load_threads",
+    "load_data_in_thread",
+    "load_thread",
+    "cuda_set_device",
+    "free_ptrs",
+    "top_k",
+    "read_tree",
+    "get_metadata",
+    "delete_json_sender",
+    "send_json_custom",
+    "get_time_point",
+    "start_timer",
+    "stop_timer",
+    "get_time",
+    "stop_timer_and_show",
+    "stop_timer_and_show_name",
+    "show_total_time",
+    "set_track_id",
+    "fill_remaining_id",
+    "init_cpu",
+];
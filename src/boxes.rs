@@ -0,0 +1,121 @@
+//! Bounding boxes and the overlap measures darknet uses for NMS and evaluation.
+//!
+//! The functions follow `box.c` operation for operation in `f32`, so results match
+//! darknet's own up to the compiler's floating point contraction.
+
+use crate::box_;
+
+/// A box given by its center `x`, `y` and its size `w`, `h`, like darknet's `box`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BBox {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+impl BBox {
+    /// Creates a box from its center and size.
+    pub fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// Creates a box from its left, top, right and bottom edges.
+    pub fn from_corners(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            x: (left + right) / 2.0,
+            y: (top + bottom) / 2.0,
+            w: right - left,
+            h: bottom - top,
+        }
+    }
+
+    /// Area of the box.
+    pub fn area(&self) -> f32 {
+        self.w * self.h
+    }
+
+    /// Area of the intersection with `other`, `box_intersection()`.
+    pub fn intersection(&self, other: &BBox) -> f32 {
+        let w = overlap(self.x, self.w, other.x, other.w);
+        let h = overlap(self.y, self.h, other.y, other.h);
+        if w < 0.0 || h < 0.0 {
+            return 0.0;
+        }
+        w * h
+    }
+
+    /// Area of the union with `other`, `box_union()`.
+    pub fn union(&self, other: &BBox) -> f32 {
+        let i = self.intersection(other);
+        self.w * self.h + other.w * other.h - i
+    }
+
+    /// Intersection over union, `box_iou()`.
+    pub fn iou(&self, other: &BBox) -> f32 {
+        let i = self.intersection(other);
+        let u = self.union(other);
+        if i == 0.0 || u == 0.0 {
+            return 0.0;
+        }
+        i / u
+    }
+
+    /// Distance-IoU as used by greedy NMS, `box_diou()`.
+    pub fn diou(&self, other: &BBox) -> f32 {
+        self.diou_with_beta(other, 0.6)
+    }
+
+    /// Distance-IoU with the distance penalty raised to `beta1`, `box_diounms()`.
+    pub fn diou_with_beta(&self, other: &BBox, beta1: f32) -> f32 {
+        let (top, bot, left, right) = enclosing(self, other);
+        let w = right - left;
+        let h = bot - top;
+        let c = w * w + h * h;
+        let iou = self.iou(other);
+        if c == 0.0 {
+            return iou;
+        }
+        let d = (self.x - other.x) * (self.x - other.x) + (self.y - other.y) * (self.y - other.y);
+        // `pow()` is evaluated in double precision.
+        let u = f64::from(d / c).powf(f64::from(beta1)) as f32;
+        iou - u
+    }
+}
+
+impl From<box_> for BBox {
+    fn from(b: box_) -> Self {
+        Self::new(b.x, b.y, b.w, b.h)
+    }
+}
+
+impl From<BBox> for box_ {
+    fn from(b: BBox) -> Self {
+        box_ {
+            x: b.x,
+            y: b.y,
+            w: b.w,
+            h: b.h,
+        }
+    }
+}
+
+fn overlap(x1: f32, w1: f32, x2: f32, w2: f32) -> f32 {
+    let l1 = x1 - w1 / 2.0;
+    let l2 = x2 - w2 / 2.0;
+    let left = if l1 > l2 { l1 } else { l2 };
+    let r1 = x1 + w1 / 2.0;
+    let r2 = x2 + w2 / 2.0;
+    let right = if r1 < r2 { r1 } else { r2 };
+    right - left
+}
+
+// `box_c()`: top, bottom, left and right of the smallest box enclosing both.
+fn enclosing(a: &BBox, b: &BBox) -> (f32, f32, f32, f32) {
+    (
+        (a.y - a.h / 2.0).min(b.y - b.h / 2.0),
+        (a.y + a.h / 2.0).max(b.y + b.h / 2.0),
+        (a.x - a.w / 2.0).min(b.x - b.w / 2.0),
+        (a.x + a.w / 2.0).max(b.x + b.w / 2.0),
+    )
+}
@@ -0,0 +1,136 @@
+//! How the libdarknet this crate uses was built and linked.
+//!
+//! The constants are generated by the build script. Values that depend on how a
+//! prebuilt library was configured, such as [`CUDA`] for the `runtime` feature, are
+//! `None` because the build script cannot know them.
+
+use std::{error, fmt};
+
+/// How libdarknet is linked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Linkage {
+    /// Built from source and linked statically, the default.
+    Static,
+    /// Linked to a shared library, built from source with `dylib` or found with
+    /// `runtime`.
+    Dynamic,
+    /// Loaded when the program runs, with `dlopen`.
+    Dlopen,
+}
+
+include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+
+/// One line describing the build, suitable for logging at startup.
+pub fn summary() -> String {
+    fn flag(name: &str, value: Option<bool>) -> String {
+        match value {
+            Some(true) => format!("{} on", name),
+            Some(false) => format!("{} off", name),
+            None => format!("{} unknown", name),
+        }
+    }
+    let mut summary = match (SOURCE, LIB_DIR) {
+        (Some(source), _) => format!("libdarknet built from {}", source),
+        (None, Some(lib_dir)) => format!("libdarknet from {}", lib_dir),
+        (None, None) => "libdarknet".to_owned(),
+    };
+    if let Some(revision) = GIT_REVISION {
+        summary.push_str(&format!(" at {}", revision));
+    }
+    if let Some(profile) = CMAKE_PROFILE {
+        summary.push_str(&format!(", {}", profile));
+    }
+    summary.push_str(&format!(
+        ", {:?}; {}, {}, {}, {}",
+        LINKAGE,
+        flag("CUDA", CUDA),
+        flag("cuDNN", CUDNN),
+        flag("OpenCV", OPENCV),
+        flag("OpenMP", OPENMP)
+    ));
+    summary
+}
+
+/// A difference between the build information and the libdarknet in the process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MismatchError {
+    /// darknet's functions come from a library outside [`LIB_DIR`].
+    Library { expected: String, actual: String },
+    /// libdarknet was built with a component whose runtime library is not loaded.
+    MissingRuntime(&'static str),
+}
+
+impl fmt::Display for MismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MismatchError::Library { expected, actual } => write!(
+                f,
+                "libdarknet was loaded from {} instead of {}",
+                actual, expected
+            ),
+            MismatchError::MissingRuntime(component) => write!(
+                f,
+                "libdarknet was built with {}, but its runtime library is not loaded",
+                component
+            ),
+        }
+    }
+}
+
+impl error::Error for MismatchError {}
+
+/// Checks that the libdarknet loaded into the process matches the build information.
+///
+/// For [`Linkage::Dynamic`] the library providing darknet's functions must be in
+/// [`LIB_DIR`], e.g. not another copy found through `LD_LIBRARY_PATH`. Components
+/// built in must have their runtime libraries loaded: OpenMP, CUDA and cuDNN.
+/// OpenCV only has a C++ API and is not checked.
+#[cfg(all(unix, not(feature = "dlopen")))]
+pub fn check() -> Result<(), MismatchError> {
+    use std::{ffi::CStr, os::raw::c_void, path::Path};
+
+    if let (Linkage::Dynamic, Some(lib_dir)) = (LINKAGE, LIB_DIR) {
+        let mut info: libc::Dl_info = unsafe { std::mem::zeroed() };
+        let found = unsafe { libc::dladdr(crate::load_network as *const c_void, &mut info) };
+        if found != 0 && !info.dli_fname.is_null() {
+            let library = unsafe { CStr::from_ptr(info.dli_fname) }.to_string_lossy();
+            let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_owned());
+            let actual_dir = Path::new(&*library).parent().map(canonical);
+            if actual_dir != Some(canonical(Path::new(lib_dir))) {
+                return Err(MismatchError::Library {
+                    expected: lib_dir.to_owned(),
+                    actual: library.into_owned(),
+                });
+            }
+        }
+    }
+
+    for &(enabled, component, symbol) in &[
+        (OPENMP, "OpenMP", &b"omp_get_max_threads\0"[..]),
+        (CUDA, "CUDA", &b"cudaGetDeviceCount\0"[..]),
+        (CUDNN, "cuDNN", &b"cudnnCreate\0"[..]),
+    ] {
+        if enabled == Some(true)
+            && unsafe { libc::dlsym(libc::RTLD_DEFAULT, symbol.as_ptr() as *const _) }.is_null()
+        {
+            return Err(MismatchError::MissingRuntime(component));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_mentions_linkage() {
+        assert!(summary().contains(&format!("{:?}", LINKAGE)));
+    }
+
+    #[cfg(all(unix, not(feature = "dlopen")))]
+    #[test]
+    fn linked_library_matches() {
+        check().unwrap();
+    }
+}
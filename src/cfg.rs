@@ -0,0 +1,492 @@
+//! Parser and writer for darknet `.cfg` network files.
+//!
+//! Parsing follows `read_cfg()`: all whitespace is removed from each line, lines
+//! starting with `#` or `;` are comments, `[name]` starts a section, and every
+//! other line is a `key=value` option. Unlike darknet, problems are reported as
+//! [`CfgError`]s carrying the 1-based line number instead of terminating the
+//! process. Writing a [`NetworkCfg`] back with `Display` keeps sections and options
+//! in their original order but drops comments.
+
+use crate::{learning_rate_policy, EnumError, ACTIVATION, LAYER_TYPE};
+use std::{error::Error, fmt, fs, io, path::Path, str::FromStr};
+
+/// Errors produced while reading a `.cfg` file.
+#[derive(Debug)]
+pub enum CfgError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// A line is neither a section header, a comment nor a `key=value` option.
+    Syntax { line: usize, content: String },
+    /// A section name that darknet does not know.
+    UnknownSection { line: usize, name: String },
+    /// An option appears before the first section.
+    OptionOutsideSection { line: usize },
+    /// The first section is not `[net]` or `[network]`, or there is none.
+    MissingNetSection,
+    /// An option value cannot be parsed as the expected type.
+    InvalidValue {
+        line: usize,
+        key: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for CfgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CfgError::Io(err) => write!(f, "failed to read cfg file: {}", err),
+            CfgError::Syntax { line, content } => {
+                write!(f, "line {}: cannot parse \"{}\"", line, content)
+            }
+            CfgError::UnknownSection { line, name } => {
+                write!(f, "line {}: unknown section [{}]", line, name)
+            }
+            CfgError::OptionOutsideSection { line } => {
+                write!(f, "line {}: option outside of any section", line)
+            }
+            CfgError::MissingNetSection => {
+                write!(f, "first section must be [net] or [network]")
+            }
+            CfgError::InvalidValue { line, key, value } => {
+                write!(f, "line {}: invalid value \"{}\" for {}", line, value, key)
+            }
+        }
+    }
+}
+
+impl Error for CfgError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CfgError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CfgError {
+    fn from(err: io::Error) -> Self {
+        CfgError::Io(err)
+    }
+}
+
+/// A `key=value` option and the line it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CfgOption {
+    pub key: String,
+    pub value: String,
+    pub line: usize,
+}
+
+/// A `[section]` with its options in file order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Section name as written, without brackets, e.g. `conv` or `convolutional`.
+    pub name: String,
+    /// Line of the section header.
+    pub line: usize,
+    pub options: Vec<CfgOption>,
+}
+
+impl Section {
+    /// Creates an empty section.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            line: 0,
+            options: Vec::new(),
+        }
+    }
+
+    /// Raw value of `key`. Like darknet, the first occurrence wins.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.find(key).map(|option| option.value.as_str())
+    }
+
+    /// Parses the value of `key`, or returns `None` if it is absent.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, CfgError> {
+        self.find(key)
+            .map(|option| {
+                option.value.parse().map_err(|_| CfgError::InvalidValue {
+                    line: option.line,
+                    key: option.key.clone(),
+                    value: option.value.clone(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Parses the value of `key`, or returns `default` if it is absent.
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> Result<T, CfgError> {
+        Ok(self.get(key)?.unwrap_or(default))
+    }
+
+    /// Parses a comma separated list such as `anchors` or `mask`. Empty items are
+    /// skipped, as darknet does.
+    pub fn get_list<T: FromStr>(&self, key: &str) -> Result<Option<Vec<T>>, CfgError> {
+        self.find(key)
+            .map(|option| {
+                option
+                    .value
+                    .split(',')
+                    .filter(|item| !item.is_empty())
+                    .map(|item| {
+                        item.parse().map_err(|_| CfgError::InvalidValue {
+                            line: option.line,
+                            key: option.key.clone(),
+                            value: option.value.clone(),
+                        })
+                    })
+                    .collect()
+            })
+            .transpose()
+    }
+
+    /// Sets `key` to `value`, replacing the first existing occurrence.
+    pub fn set<K: Into<String>, V: ToString>(&mut self, key: K, value: V) {
+        let key = key.into();
+        let value = value.to_string();
+        match self.options.iter_mut().find(|option| option.key == key) {
+            Some(option) => option.value = value,
+            None => self.options.push(CfgOption {
+                key,
+                value,
+                line: 0,
+            }),
+        }
+    }
+
+    fn find(&self, key: &str) -> Option<&CfgOption> {
+        self.options.iter().find(|option| option.key == key)
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[{}]", self.name)?;
+        for option in &self.options {
+            writeln!(f, "{}={}", option.key, option.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `[net]` section holding the network's hyperparameters.
+///
+/// Accessors return darknet's defaults from `parse_net_options()` for absent keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetSection {
+    pub section: Section,
+}
+
+impl NetSection {
+    /// Total batch size, `batch`.
+    pub fn batch(&self) -> Result<usize, CfgError> {
+        self.section.get_or("batch", 1)
+    }
+
+    /// Number of mini-batches each batch is split into, `subdivisions`.
+    pub fn subdivisions(&self) -> Result<usize, CfgError> {
+        self.section.get_or("subdivisions", 1)
+    }
+
+    /// Input width, `width`.
+    pub fn width(&self) -> Result<usize, CfgError> {
+        self.section.get_or("width", 0)
+    }
+
+    /// Input height, `height`.
+    pub fn height(&self) -> Result<usize, CfgError> {
+        self.section.get_or("height", 0)
+    }
+
+    /// Input channels, `channels`.
+    pub fn channels(&self) -> Result<usize, CfgError> {
+        self.section.get_or("channels", 0)
+    }
+
+    /// `learning_rate`.
+    pub fn learning_rate(&self) -> Result<f32, CfgError> {
+        self.section.get_or("learning_rate", 0.001)
+    }
+
+    /// `momentum`.
+    pub fn momentum(&self) -> Result<f32, CfgError> {
+        self.section.get_or("momentum", 0.9)
+    }
+
+    /// Weight decay, `decay`.
+    pub fn decay(&self) -> Result<f32, CfgError> {
+        self.section.get_or("decay", 0.0001)
+    }
+
+    /// Number of warm-up iterations, `burn_in`.
+    pub fn burn_in(&self) -> Result<usize, CfgError> {
+        self.section.get_or("burn_in", 0)
+    }
+
+    /// Training length in iterations, `max_batches`.
+    pub fn max_batches(&self) -> Result<usize, CfgError> {
+        self.section.get_or("max_batches", 0)
+    }
+
+    /// Learning rate schedule, `policy`.
+    pub fn policy(&self) -> Result<learning_rate_policy, CfgError> {
+        self.section
+            .get("policy")
+            .map(|policy| policy.unwrap_or(learning_rate_policy::CONSTANT))
+    }
+
+    /// Iterations at which the `steps` policy scales the learning rate.
+    pub fn steps(&self) -> Result<Vec<i64>, CfgError> {
+        Ok(self.section.get_list("steps")?.unwrap_or_default())
+    }
+
+    /// Factors applied at each of [`NetSection::steps`].
+    pub fn scales(&self) -> Result<Vec<f32>, CfgError> {
+        Ok(self.section.get_list("scales")?.unwrap_or_default())
+    }
+}
+
+/// A layer section such as `[convolutional]` or `[yolo]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerSection {
+    pub layer_type: LAYER_TYPE,
+    pub section: Section,
+}
+
+impl LayerSection {
+    /// `activation`, or `None` if the layer does not set one.
+    pub fn activation(&self) -> Result<Option<ACTIVATION>, CfgError> {
+        self.section.get("activation")
+    }
+
+    /// `filters` of convolutional layers.
+    pub fn filters(&self) -> Result<Option<usize>, CfgError> {
+        self.section.get("filters")
+    }
+
+    /// Kernel `size`.
+    pub fn size(&self) -> Result<Option<usize>, CfgError> {
+        self.section.get("size")
+    }
+
+    /// `stride`.
+    pub fn stride(&self) -> Result<Option<usize>, CfgError> {
+        self.section.get("stride")
+    }
+
+    /// `classes` of detection layers.
+    pub fn classes(&self) -> Result<Option<usize>, CfgError> {
+        self.section.get("classes")
+    }
+
+    /// `anchors` of detection layers, as a flat list of width, height pairs.
+    pub fn anchors(&self) -> Result<Option<Vec<f32>>, CfgError> {
+        self.section.get_list("anchors")
+    }
+
+    /// `mask` of YOLO layers, the indices of the anchors the layer uses.
+    pub fn mask(&self) -> Result<Option<Vec<usize>>, CfgError> {
+        self.section.get_list("mask")
+    }
+
+    /// `layers` of route and shortcut layers. Negative values are relative.
+    pub fn layers(&self) -> Result<Option<Vec<i64>>, CfgError> {
+        self.section.get_list("layers")
+    }
+}
+
+/// A parsed `.cfg` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkCfg {
+    pub net: NetSection,
+    pub layers: Vec<LayerSection>,
+}
+
+impl NetworkCfg {
+    /// Reads and parses the file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, CfgError> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Number of classes, taken from the first layer that sets `classes`.
+    pub fn classes(&self) -> Result<Option<usize>, CfgError> {
+        for layer in &self.layers {
+            if let Some(classes) = layer.classes()? {
+                return Ok(Some(classes));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The YOLO and Gaussian YOLO layers.
+    pub fn yolo_layers(&self) -> impl Iterator<Item = &LayerSection> {
+        self.layers.iter().filter(|layer| {
+            matches!(
+                layer.layer_type,
+                LAYER_TYPE::YOLO | LAYER_TYPE::GAUSSIAN_YOLO
+            )
+        })
+    }
+}
+
+impl FromStr for NetworkCfg {
+    type Err = CfgError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections: Vec<Section> = Vec::new();
+        for (index, raw) in s.lines().enumerate() {
+            let line = index + 1;
+            let content: String = raw.chars().filter(|c| !c.is_whitespace()).collect();
+            match content.chars().next() {
+                None | Some('#') | Some(';') => {}
+                Some('[') => {
+                    let name = content
+                        .strip_prefix('[')
+                        .and_then(|rest| rest.strip_suffix(']'))
+                        .ok_or_else(|| CfgError::Syntax {
+                            line,
+                            content: content.clone(),
+                        })?;
+                    let mut section = Section::new(name);
+                    section.line = line;
+                    sections.push(section);
+                }
+                Some(_) => {
+                    let (key, value) = split_option(&content).ok_or_else(|| CfgError::Syntax {
+                        line,
+                        content: content.clone(),
+                    })?;
+                    let section = sections
+                        .last_mut()
+                        .ok_or(CfgError::OptionOutsideSection { line })?;
+                    section.options.push(CfgOption {
+                        key: key.to_owned(),
+                        value: value.to_owned(),
+                        line,
+                    });
+                }
+            }
+        }
+
+        let mut sections = sections.into_iter();
+        let net = match sections.next() {
+            Some(section) if section.name == "net" || section.name == "network" => {
+                NetSection { section }
+            }
+            _ => return Err(CfgError::MissingNetSection),
+        };
+        let layers = sections
+            .map(|section| {
+                let layer_type =
+                    section
+                        .name
+                        .parse()
+                        .map_err(|_: EnumError| CfgError::UnknownSection {
+                            line: section.line,
+                            name: section.name.clone(),
+                        })?;
+                if layer_type == LAYER_TYPE::NETWORK {
+                    return Err(CfgError::UnknownSection {
+                        line: section.line,
+                        name: section.name.clone(),
+                    });
+                }
+                Ok(LayerSection {
+                    layer_type,
+                    section,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { net, layers })
+    }
+}
+
+impl fmt::Display for NetworkCfg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.net.section)?;
+        for layer in &self.layers {
+            writeln!(f)?;
+            write!(f, "{}", layer.section)?;
+        }
+        Ok(())
+    }
+}
+
+// `read_option()`: split at the first `=`. Both sides may be empty.
+fn split_option(line: &str) -> Option<(&str, &str)> {
+    let index = line.find('=')?;
+    Some((&line[..index], &line[index + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CFG: &str = "\
+[net]
+# Training
+batch=64
+subdivisions = 16
+width=416
+height=416
+channels=3
+policy=steps
+steps=400000,450000
+
+[convolutional]
+batch_normalize=1
+filters=32
+size=3
+stride=1
+activation=leaky
+
+[yolo]
+mask = 0,1,2
+anchors = 10,13,  16,30,  33,23
+classes=80
+";
+
+    #[test]
+    fn parses_net_and_layers() {
+        let cfg: NetworkCfg = CFG.parse().unwrap();
+        assert_eq!(cfg.net.batch().unwrap(), 64);
+        assert_eq!(cfg.net.subdivisions().unwrap(), 16);
+        assert_eq!(cfg.net.width().unwrap(), 416);
+        assert_eq!(cfg.net.policy().unwrap(), learning_rate_policy::STEPS);
+        assert_eq!(cfg.net.steps().unwrap(), vec![400000, 450000]);
+        assert_eq!(cfg.layers.len(), 2);
+        assert_eq!(cfg.layers[0].layer_type, LAYER_TYPE::CONVOLUTIONAL);
+        assert_eq!(cfg.layers[0].activation().unwrap(), Some(ACTIVATION::LEAKY));
+        assert_eq!(cfg.layers[1].mask().unwrap(), Some(vec![0, 1, 2]));
+        assert_eq!(
+            cfg.layers[1].anchors().unwrap(),
+            Some(vec![10.0, 13.0, 16.0, 30.0, 33.0, 23.0])
+        );
+        assert_eq!(cfg.classes().unwrap(), Some(80));
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        let cfg: NetworkCfg = CFG.parse().unwrap();
+        let written = cfg.to_string();
+        let reparsed: NetworkCfg = written.parse().unwrap();
+        assert_eq!(reparsed.to_string(), written);
+        assert_eq!(reparsed.layers[1].section.get_str("mask"), Some("0,1,2"));
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        let err = "[net]\nwidth=416\n[bogus]\n"
+            .parse::<NetworkCfg>()
+            .unwrap_err();
+        assert!(matches!(err, CfgError::UnknownSection { line: 3, .. }));
+
+        let cfg: NetworkCfg = "[net]\n\nwidth=abc\n".parse().unwrap();
+        let err = cfg.net.width().unwrap_err();
+        assert!(matches!(err, CfgError::InvalidValue { line: 3, .. }));
+
+        let err = "[net]\nnot an option\n".parse::<NetworkCfg>().unwrap_err();
+        assert!(matches!(err, CfgError::Syntax { line: 2, .. }));
+    }
+}
@@ -0,0 +1,158 @@
+//! Loading libdarknet at runtime with the `dlopen` feature.
+//!
+//! With this feature the bindings do not declare any `extern` functions. They are
+//! instead fields and methods of [`Darknet`], which resolves every symbol when the
+//! library is opened.
+//!
+//! Functions that darknet only defines in GPU builds, such as `cuda_pull_array`,
+//! are left out of the bindings so that CPU builds can be loaded.
+
+use crate::{Darknet, DARKNET_SYMBOLS};
+use std::{
+    error::Error,
+    ffi::{OsStr, OsString},
+    fmt,
+    os::raw::c_void,
+};
+
+/// File names tried by [`Darknet::open_default`], in order.
+///
+/// darknet's CMake build installs `libdark`, its Makefile builds `libdarknet`.
+pub fn default_library_names() -> Vec<OsString> {
+    vec![
+        libloading::library_filename("darknet"),
+        libloading::library_filename("dark"),
+    ]
+}
+
+/// Error returned when libdarknet cannot be loaded.
+#[derive(Debug)]
+pub enum DlopenError {
+    /// None of the candidate libraries could be opened.
+    NotFound(Vec<(OsString, libloading::Error)>),
+    /// The library was opened but does not export all functions of the bindings.
+    MissingSymbols {
+        library: OsString,
+        symbols: Vec<&'static str>,
+    },
+}
+
+impl fmt::Display for DlopenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DlopenError::NotFound(attempts) => {
+                write!(f, "failed to load libdarknet")?;
+                for (library, err) in attempts {
+                    write!(f, "\n  {}: {}", library.to_string_lossy(), err)?;
+                }
+                Ok(())
+            }
+            DlopenError::MissingSymbols { library, symbols } => write!(
+                f,
+                "{} is missing required symbols: {}",
+                library.to_string_lossy(),
+                symbols.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for DlopenError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DlopenError::NotFound(attempts) if attempts.len() == 1 => Some(&attempts[0].1),
+            _ => None,
+        }
+    }
+}
+
+impl Darknet {
+    /// Loads libdarknet from `path`.
+    ///
+    /// A bare file name is looked up in the platform's library search path.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialization routines. The library must be a
+    /// libdarknet build whose ABI matches the bindings of this crate.
+    pub unsafe fn open<P>(path: P) -> Result<Self, DlopenError>
+    where
+        P: AsRef<OsStr>,
+    {
+        let path = path.as_ref();
+        let library = libloading::Library::new(path)
+            .map_err(|err| DlopenError::NotFound(vec![(path.to_owned(), err)]))?;
+        Self::from_checked_library(path, library)
+    }
+
+    /// Loads libdarknet from the first of [`default_library_names`] found in the
+    /// library search path.
+    ///
+    /// # Safety
+    ///
+    /// See [`Darknet::open`].
+    pub unsafe fn open_default() -> Result<Self, DlopenError> {
+        let mut attempts = Vec::new();
+        for name in default_library_names() {
+            match libloading::Library::new(&name) {
+                Ok(library) => return Self::from_checked_library(&name, library),
+                Err(err) => attempts.push((name, err)),
+            }
+        }
+        Err(DlopenError::NotFound(attempts))
+    }
+
+    unsafe fn from_checked_library(
+        path: &OsStr,
+        library: libloading::Library,
+    ) -> Result<Self, DlopenError> {
+        let symbols = missing_symbols(|name| {
+            let mut symbol = name.as_bytes().to_vec();
+            symbol.push(0);
+            library.get::<*mut c_void>(&symbol).is_ok()
+        });
+        if !symbols.is_empty() {
+            return Err(DlopenError::MissingSymbols {
+                library: path.to_owned(),
+                symbols,
+            });
+        }
+        // Every symbol resolved above, so this only fails if the library changed
+        // underneath us.
+        Self::from_library(library)
+            .map_err(|err| DlopenError::NotFound(vec![(path.to_owned(), err)]))
+    }
+}
+
+// The symbols of the bindings for which `is_exported` is false.
+fn missing_symbols<F>(is_exported: F) -> Vec<&'static str>
+where
+    F: Fn(&str) -> bool,
+{
+    DARKNET_SYMBOLS
+        .iter()
+        .copied()
+        .filter(|name| !is_exported(name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The `cuda_*` functions a CPU-only libdarknet exports.
+    const CPU_CUDA_SYMBOLS: &[&str] = &["cuda_set_device"];
+
+    #[test]
+    fn cpu_only_libraries_have_every_symbol() {
+        let cpu_only = |name: &str| !name.starts_with("cuda_") || CPU_CUDA_SYMBOLS.contains(&name);
+        assert_eq!(missing_symbols(cpu_only), Vec::<&str>::new());
+        assert!(DARKNET_SYMBOLS.contains(&"cuda_set_device"));
+    }
+
+    #[test]
+    fn reports_missing_symbols_by_name() {
+        let old = |name: &str| name != "network_predict_image_letterbox";
+        assert_eq!(missing_symbols(old), ["network_predict_image_letterbox"]);
+    }
+}
@@ -0,0 +1,306 @@
+//! Conversions for the darknet enums generated as Rust enums.
+//!
+//! String forms follow the spellings darknet itself accepts in `.cfg` files
+//! (see `parser.c`, `activations.c` and `cost_layer.c`). `Display` produces the
+//! canonical spelling; `FromStr` also accepts darknet's aliases.
+
+use crate::{
+    data_type, learning_rate_policy, ACTIVATION, COST_TYPE, IMTYPE, IOU_LOSS, LAYER_TYPE, NMS_KIND,
+};
+use std::{convert::TryFrom, error::Error, fmt, str::FromStr};
+
+/// Error returned when a value does not correspond to any variant of a darknet enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnumError {
+    /// The integer is not a discriminant of the enum.
+    InvalidValue { name: &'static str, value: u32 },
+    /// The string is not a known spelling of any variant.
+    UnknownName { name: &'static str, value: String },
+}
+
+impl fmt::Display for EnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnumError::InvalidValue { name, value } => {
+                write!(f, "{} is not a valid {} value", value, name)
+            }
+            EnumError::UnknownName { name, value } => {
+                write!(f, "unknown {} \"{}\"", name, value)
+            }
+        }
+    }
+}
+
+impl Error for EnumError {}
+
+macro_rules! darknet_enum {
+    ($name:ident { $($variant:ident => $display:literal $(| $alias:literal)*,)+ }) => {
+        impl $name {
+            /// All variants in discriminant order.
+            pub const ALL: &'static [$name] = &[$($name::$variant),+];
+
+            /// The spelling darknet uses for this variant in `.cfg` files.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $($name::$variant => $display,)+
+                }
+            }
+        }
+
+        impl TryFrom<u32> for $name {
+            type Error = EnumError;
+
+            fn try_from(value: u32) -> Result<Self, Self::Error> {
+                $(if value == $name::$variant as u32 {
+                    return Ok($name::$variant);
+                })+
+                Err(EnumError::InvalidValue {
+                    name: stringify!($name),
+                    value,
+                })
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(value: $name) -> u32 {
+                value as u32
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = EnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.trim() {
+                    $($display $(| $alias)* => Ok($name::$variant),)+
+                    _ => Err(EnumError::UnknownName {
+                        name: stringify!($name),
+                        value: s.to_owned(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+// Section names without brackets, as in `string_to_layer_type()`.
+// Layer types that cannot appear in a cfg use their lowercase name.
+darknet_enum!(LAYER_TYPE {
+    CONVOLUTIONAL => "convolutional" | "conv",
+    DECONVOLUTIONAL => "deconvolutional" | "deconv",
+    CONNECTED => "connected" | "conn",
+    MAXPOOL => "maxpool" | "max",
+    LOCAL_AVGPOOL => "local_avgpool" | "local_avg",
+    SOFTMAX => "softmax" | "soft",
+    DETECTION => "detection",
+    DROPOUT => "dropout",
+    CROP => "crop",
+    ROUTE => "route",
+    COST => "cost",
+    NORMALIZATION => "normalization" | "lrn",
+    AVGPOOL => "avgpool" | "avg",
+    LOCAL => "local",
+    SHORTCUT => "shortcut",
+    SCALE_CHANNELS => "scale_channels",
+    SAM => "sam",
+    ACTIVE => "activation",
+    RNN => "rnn",
+    GRU => "gru",
+    LSTM => "lstm",
+    CONV_LSTM => "conv_lstm",
+    HISTORY => "history",
+    CRNN => "crnn",
+    BATCHNORM => "batchnorm",
+    NETWORK => "net" | "network",
+    XNOR => "xnor",
+    REGION => "region",
+    YOLO => "yolo",
+    GAUSSIAN_YOLO => "Gaussian_yolo",
+    ISEG => "iseg",
+    REORG => "reorg3d",
+    REORG_OLD => "reorg",
+    UPSAMPLE => "upsample",
+    LOGXENT => "logistic",
+    L2NORM => "l2norm",
+    EMPTY => "empty" | "silence",
+    BLANK => "blank",
+    CONTRASTIVE => "contrastive",
+    IMPLICIT => "implicit_add" | "implicit_mul",
+});
+
+// `get_activation()` / `get_activation_string()`
+darknet_enum!(ACTIVATION {
+    LOGISTIC => "logistic",
+    RELU => "relu",
+    RELU6 => "relu6",
+    RELIE => "relie",
+    LINEAR => "linear",
+    RAMP => "ramp",
+    TANH => "tanh",
+    PLSE => "plse",
+    REVLEAKY => "revleaky",
+    LEAKY => "leaky",
+    ELU => "elu",
+    LOGGY => "loggy",
+    STAIR => "stair",
+    HARDTAN => "hardtan",
+    LHTAN => "lhtan",
+    SELU => "selu",
+    GELU => "gelu",
+    SWISH => "swish",
+    MISH => "mish",
+    HARD_MISH => "hard_mish",
+    NORM_CHAN => "normalize_channels",
+    NORM_CHAN_SOFTMAX => "normalize_channels_softmax",
+    NORM_CHAN_SOFTMAX_MAXVAL => "normalize_channels_softmax_maxval",
+});
+
+// `nms_kind=` option of [yolo] and [Gaussian_yolo]
+darknet_enum!(NMS_KIND {
+    DEFAULT_NMS => "default",
+    GREEDY_NMS => "greedynms",
+    DIOU_NMS => "diounms",
+    CORNERS_NMS => "cornersnms",
+});
+
+// `get_cost_type()` / `get_cost_string()`
+darknet_enum!(COST_TYPE {
+    SSE => "sse",
+    MASKED => "masked",
+    L1 => "L1",
+    SEG => "seg",
+    SMOOTH => "smooth",
+    WGAN => "wgan",
+});
+
+// `iou_loss=` option of [yolo] and [Gaussian_yolo]
+darknet_enum!(IOU_LOSS {
+    IOU => "iou",
+    GIOU => "giou",
+    MSE => "mse",
+    DIOU => "diou",
+    CIOU => "ciou",
+});
+
+// File extensions used by `save_image_options()`
+darknet_enum!(IMTYPE {
+    PNG => "png",
+    BMP => "bmp",
+    TGA => "tga",
+    JPG => "jpg" | "jpeg",
+});
+
+// `get_policy()`
+darknet_enum!(learning_rate_policy {
+    CONSTANT => "constant",
+    STEP => "step",
+    EXP => "exp",
+    POLY => "poly",
+    STEPS => "steps",
+    SIG => "sigmoid",
+    RANDOM => "random",
+    SGDR => "sgdr",
+});
+
+// Not a cfg option; lowercase names without the `_DATA` suffix.
+darknet_enum!(data_type {
+    CLASSIFICATION_DATA => "classification",
+    DETECTION_DATA => "detection",
+    CAPTCHA_DATA => "captcha",
+    REGION_DATA => "region",
+    IMAGE_DATA => "image",
+    COMPARE_DATA => "compare",
+    WRITING_DATA => "writing",
+    SWAG_DATA => "swag",
+    TAG_DATA => "tag",
+    OLD_CLASSIFICATION_DATA => "old_classification",
+    STUDY_DATA => "study",
+    DET_DATA => "det",
+    SUPER_DATA => "super",
+    LETTERBOX_DATA => "letterbox",
+    REGRESSION_DATA => "regression",
+    SEGMENTATION_DATA => "segmentation",
+    INSTANCE_DATA => "instance",
+    ISEG_DATA => "iseg",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! assert_round_trips {
+        ($($name:ident),+) => {$(
+            for &variant in $name::ALL {
+                assert_eq!($name::try_from(u32::from(variant)), Ok(variant));
+                assert_eq!(variant.as_str().parse::<$name>(), Ok(variant));
+                assert_eq!(variant.to_string(), variant.as_str());
+            }
+            let max = $name::ALL.iter().map(|&v| u32::from(v)).max().unwrap();
+            for &value in &[max + 1, u32::MAX] {
+                assert_eq!(
+                    $name::try_from(value),
+                    Err(EnumError::InvalidValue {
+                        name: stringify!($name),
+                        value,
+                    })
+                );
+            }
+        )+};
+    }
+
+    #[test]
+    fn round_trips_all_variants() {
+        assert_round_trips!(
+            LAYER_TYPE,
+            ACTIVATION,
+            NMS_KIND,
+            COST_TYPE,
+            IOU_LOSS,
+            IMTYPE,
+            learning_rate_policy,
+            data_type
+        );
+    }
+
+    #[test]
+    fn accepts_aliases() {
+        assert_eq!("conv".parse(), Ok(LAYER_TYPE::CONVOLUTIONAL));
+        assert_eq!("lrn".parse(), Ok(LAYER_TYPE::NORMALIZATION));
+        assert_eq!("network".parse(), Ok(LAYER_TYPE::NETWORK));
+        assert_eq!("silence".parse(), Ok(LAYER_TYPE::EMPTY));
+        assert_eq!("implicit_mul".parse(), Ok(LAYER_TYPE::IMPLICIT));
+        assert_eq!("jpeg".parse(), Ok(IMTYPE::JPG));
+        // Surrounding whitespace is ignored, as in cfg files.
+        assert_eq!(" leaky ".parse(), Ok(ACTIVATION::LEAKY));
+        // The canonical spelling is used for display.
+        assert_eq!(LAYER_TYPE::NETWORK.to_string(), "net");
+        assert_eq!(IMTYPE::JPG.to_string(), "jpg");
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert_eq!(
+            "Leaky".parse::<ACTIVATION>(),
+            Err(EnumError::UnknownName {
+                name: "ACTIVATION",
+                value: "Leaky".to_owned(),
+            })
+        );
+        assert!("".parse::<NMS_KIND>().is_err());
+        assert!("[convolutional]".parse::<LAYER_TYPE>().is_err());
+        assert_eq!(
+            EnumError::InvalidValue {
+                name: "IMTYPE",
+                value: 9
+            }
+            .to_string(),
+            "9 is not a valid IMTYPE value"
+        );
+    }
+}
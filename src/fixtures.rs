@@ -0,0 +1,193 @@
+// Test data for the `against_c` tests, which call into libdarknet.
+//
+// Those tests only build without `runtime`, `dlopen` and `docs-rs`: then
+// libdarknet is compiled from source with the bundled headers and linked
+// statically, so the tests know which darknet they run against. `runtime` and
+// `dlopen` use whatever library is installed, which may be missing or a stub, and
+// `docs-rs` builds no library at all.
+
+#[cfg(feature = "safe")]
+use crate::{box_, detection};
+use crate::{boxes::BBox, metrics::Truth};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+#[cfg(feature = "safe")]
+use std::{mem, os::raw::c_int, ptr};
+
+// A network for the images of `write_bmp`, with a YOLO layer for 2 classes. Only
+// the `safe` tests load it.
+#[cfg(feature = "safe")]
+pub(crate) const YOLO_CFG: &str = "[net]
+batch=1
+subdivisions=1
+width=32
+height=32
+channels=3
+
+[convolutional]
+batch_normalize=1
+filters=8
+size=3
+stride=2
+pad=1
+activation=leaky
+
+[convolutional]
+filters=21
+size=1
+stride=1
+pad=1
+activation=linear
+
+[yolo]
+mask=0,1,2
+anchors=4,4, 8,8, 16,16
+classes=2
+num=3
+";
+
+// Writes `cfg` to a file in the temporary directory.
+#[cfg(feature = "safe")]
+pub(crate) fn cfg_file(name: &str, cfg: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("darknet-sys-{}.cfg", name));
+    fs::write(&path, cfg).unwrap();
+    path
+}
+
+// Allocates detections like `make_network_boxes`, to be freed by
+// `free_detections`: one per entry of `probs` with those class probabilities, a
+// zeroed mask of `mask_len` values, the box at index `i` centered at (0.1 * i,
+// 0.1 * i) with size 0.2 and objectness 0.9.
+#[cfg(feature = "safe")]
+pub(crate) fn make_detections(probs: &[&[f32]], mask_len: usize) -> *mut detection {
+    unsafe {
+        let dets = libc::calloc(probs.len(), mem::size_of::<detection>()) as *mut detection;
+        assert!(!dets.is_null() || probs.is_empty());
+        for (index, prob) in probs.iter().enumerate() {
+            let det = &mut *dets.add(index);
+            let center = 0.1 * index as f32;
+            det.bbox = box_ {
+                x: center,
+                y: center,
+                w: 0.2,
+                h: 0.2,
+            };
+            det.objectness = 0.9;
+            det.classes = prob.len() as c_int;
+            det.prob = libc::calloc(prob.len(), mem::size_of::<f32>()) as *mut f32;
+            if !prob.is_empty() {
+                ptr::copy_nonoverlapping(prob.as_ptr(), det.prob, prob.len());
+            }
+            if mask_len > 0 {
+                det.mask = libc::calloc(mask_len, mem::size_of::<f32>()) as *mut f32;
+            }
+        }
+        dets
+    }
+}
+
+// Size of the images `write_bmp` writes.
+pub(crate) const IMAGE_SIZE: usize = 32;
+
+// Writes a 32x32 24-bit BMP, which darknet can load without OpenCV, with a bright
+// 16x16 square at (`offset`, `offset`) on a dark background.
+pub(crate) fn write_bmp(path: &Path, offset: usize) {
+    let (w, h) = (IMAGE_SIZE as u32, IMAGE_SIZE as u32);
+    let row = (w * 3) as usize;
+    let size = 54 + row * h as usize;
+    let mut bmp = Vec::with_capacity(size);
+    bmp.extend_from_slice(b"BM");
+    bmp.extend_from_slice(&(size as u32).to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&54u32.to_le_bytes());
+    bmp.extend_from_slice(&40u32.to_le_bytes());
+    bmp.extend_from_slice(&w.to_le_bytes());
+    bmp.extend_from_slice(&h.to_le_bytes());
+    bmp.extend_from_slice(&1u16.to_le_bytes());
+    bmp.extend_from_slice(&24u16.to_le_bytes());
+    bmp.extend_from_slice(&[0; 24]);
+    for y in 0..h as usize {
+        for x in 0..w as usize {
+            let inside = (offset..offset + 16).contains(&x) && (offset..offset + 16).contains(&y);
+            let value = if inside { 230 } else { 20 };
+            bmp.extend_from_slice(&[value; 3]);
+        }
+    }
+    fs::write(path, bmp).unwrap();
+}
+
+// The ground-truth box of the square `write_bmp` draws at `offset`.
+pub(crate) fn square(class_id: usize, offset: usize) -> Truth {
+    let center = (offset as f32 + 8.0) / IMAGE_SIZE as f32;
+    Truth::new(class_id, BBox::new(center, center, 0.5, 0.5))
+}
+
+// A dataset in a fresh temporary directory.
+pub(crate) struct Dataset {
+    pub data: PathBuf,
+    pub cfg: PathBuf,
+    // The images with their ground truth, which only the `safe` tests detect on.
+    #[cfg(feature = "safe")]
+    pub images: Vec<(PathBuf, Vec<Truth>)>,
+}
+
+// Writes one image per entry of `images`, with its square at the given offset and
+// the label file next to it where darknet looks for it, the image list as both
+// `train` and `valid`, the class names, a `backup` directory, `obj.data` and the
+// network as `tiny.cfg`.
+pub(crate) fn dataset(
+    name: &str,
+    cfg: &str,
+    names: &[&str],
+    images: &[(usize, Vec<Truth>)],
+) -> Dataset {
+    let dir = env::temp_dir().join(format!("darknet-sys-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    for sub in &["images", "backup"] {
+        fs::create_dir_all(dir.join(sub)).unwrap();
+    }
+    let mut list = String::new();
+    for (index, (offset, truths)) in images.iter().enumerate() {
+        let image = image_path(&dir, index);
+        write_bmp(&image, *offset);
+        let labels: String = truths
+            .iter()
+            .map(|t| {
+                format!(
+                    "{} {} {} {} {}\n",
+                    t.class_id, t.bbox.x, t.bbox.y, t.bbox.w, t.bbox.h
+                )
+            })
+            .collect();
+        fs::write(image.with_extension("txt"), labels).unwrap();
+        list.push_str(&format!("{}\n", image.display()));
+    }
+    fs::write(dir.join("list.txt"), list).unwrap();
+    fs::write(dir.join("obj.names"), names.join("\n") + "\n").unwrap();
+    fs::write(dir.join("tiny.cfg"), cfg).unwrap();
+    fs::write(
+        dir.join("obj.data"),
+        format!(
+            "classes={1}\ntrain={0}/list.txt\nvalid={0}/list.txt\nnames={0}/obj.names\nbackup={0}/backup\n",
+            dir.display(),
+            names.len()
+        ),
+    )
+    .unwrap();
+    Dataset {
+        data: dir.join("obj.data"),
+        cfg: dir.join("tiny.cfg"),
+        #[cfg(feature = "safe")]
+        images: images
+            .iter()
+            .enumerate()
+            .map(|(index, (_, truths))| (image_path(&dir, index), truths.clone()))
+            .collect(),
+    }
+}
+
+fn image_path(dir: &Path, index: usize) -> PathBuf {
+    dir.join("images").join(format!("{}.bmp", index))
+}
@@ -0,0 +1,360 @@
+//! Recovering from libdarknet's fatal errors (Unix only).
+//!
+//! libdarknet handles missing files, malformed cfgs and failed allocations by
+//! printing a message and calling `exit()`, which takes the whole process down.
+//! [`check`] runs a closure in a forked child process instead, so such an exit only
+//! ends the child and comes back as a [`FatalError`] with everything the child
+//! printed. [`call`] rehearses a call this way and, if the child got through, makes
+//! it again in the current process to return its result. That doubles the cost of
+//! the call, and the second run is unguarded (see [`call`]).
+//!
+//! ```ignore
+//! use darknet_sys::{guard, safe::Network};
+//!
+//! // Fails with a `GuardError` instead of exiting if yolov4.cfg is malformed.
+//! let net = guard::call(|| Network::load("yolov4.cfg", Some("yolov4.weights"), false))??;
+//! ```
+//!
+//! Forking copies the whole address space but only the calling thread. The closure
+//! must therefore not wait on locks or threads other than its own, which holds for
+//! darknet's loaders. With CUDA the child must not touch the GPU when the parent
+//! already initialized it.
+
+use std::{
+    error, fmt,
+    fs::File,
+    io::{self, Read, Write},
+    mem,
+    os::{raw::c_int, unix::io::FromRawFd},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+/// How the child process ended without finishing the closure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    /// The child called `exit()` with this status.
+    Exited(i32),
+    /// The child was killed by this signal, e.g. `SIGSEGV`.
+    Signaled(i32),
+    /// The closure panicked.
+    Panicked,
+}
+
+/// A call that ended the process it ran in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FatalError {
+    /// How the process ended.
+    pub termination: Termination,
+    /// Everything the call wrote to stdout and stderr.
+    pub output: String,
+}
+
+impl FatalError {
+    /// darknet's error message: the output from the last `Darknet error location`
+    /// line on, or else the last non-empty line.
+    pub fn message(&self) -> &str {
+        let output = self.output.trim_end();
+        if let Some(start) = output.rfind("Darknet error location") {
+            return &output[start..];
+        }
+        output
+            .lines()
+            .rev()
+            .find(|line| !line.trim().is_empty())
+            .map_or("", str::trim)
+    }
+}
+
+impl fmt::Display for FatalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.termination {
+            Termination::Exited(status) => write!(f, "darknet exited with status {}", status)?,
+            Termination::Signaled(signal) => write!(f, "darknet was killed by signal {}", signal)?,
+            Termination::Panicked => f.write_str("darknet call panicked")?,
+        }
+        match self.message() {
+            "" => Ok(()),
+            message => write!(f, ": {}", message),
+        }
+    }
+}
+
+impl error::Error for FatalError {}
+
+/// Errors of [`check`] and [`call`].
+#[derive(Debug)]
+pub enum GuardError {
+    /// The call ended its process.
+    Fatal(FatalError),
+    /// Creating pipes or forking the child failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for GuardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuardError::Fatal(err) => write!(f, "{}", err),
+            GuardError::Io(err) => write!(f, "cannot run guarded call: {}", err),
+        }
+    }
+}
+
+impl error::Error for GuardError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            GuardError::Fatal(err) => Some(err),
+            GuardError::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for GuardError {
+    fn from(err: io::Error) -> Self {
+        GuardError::Io(err)
+    }
+}
+
+/// Runs `f` in a forked child process and reports whether it returned.
+///
+/// The child's result, memory and output are discarded if it returns.
+pub fn check<F, T>(f: F) -> Result<(), GuardError>
+where
+    F: FnOnce() -> T,
+{
+    let (output_read, output_write) = pipe()?;
+    let (status_read, status_write) = match pipe() {
+        Ok(status) => status,
+        Err(err) => {
+            close(output_read);
+            close(output_write);
+            return Err(err.into());
+        }
+    };
+    // Unflushed stdio buffers would otherwise be written by both processes.
+    unsafe { libc::fflush(ptr::null_mut()) };
+    let _ = io::stdout().flush();
+
+    let pid = unsafe { libc::fork() };
+    if pid == 0 {
+        unsafe {
+            libc::close(output_read);
+            libc::close(status_read);
+            libc::dup2(output_write, libc::STDOUT_FILENO);
+            libc::dup2(output_write, libc::STDERR_FILENO);
+            libc::close(output_write);
+        }
+        let returned = panic::catch_unwind(AssertUnwindSafe(|| mem::forget(f()))).is_ok();
+        unsafe {
+            libc::fflush(ptr::null_mut());
+            if returned {
+                libc::write(status_write, [1u8].as_ptr() as *const _, 1);
+            }
+            libc::_exit(if returned { 0 } else { 101 });
+        }
+    }
+    close(output_write);
+    close(status_write);
+    if pid < 0 {
+        let err = io::Error::last_os_error();
+        close(output_read);
+        close(status_read);
+        return Err(err.into());
+    }
+
+    let mut output = Vec::new();
+    let _ = unsafe { File::from_raw_fd(output_read) }.read_to_end(&mut output);
+    let mut returned = Vec::new();
+    let _ = unsafe { File::from_raw_fd(status_read) }.read_to_end(&mut returned);
+    let status = wait(pid)?;
+    if !returned.is_empty() {
+        return Ok(());
+    }
+    let termination = if libc::WIFEXITED(status) {
+        match libc::WEXITSTATUS(status) {
+            101 => Termination::Panicked,
+            code => Termination::Exited(code),
+        }
+    } else {
+        Termination::Signaled(libc::WTERMSIG(status))
+    };
+    Err(GuardError::Fatal(FatalError {
+        termination,
+        output: String::from_utf8_lossy(&output).into_owned(),
+    }))
+}
+
+/// Runs `f` in a child process with [`check`], then, if it returned, again in the
+/// current process, returning its result.
+///
+/// `f` runs twice, so it should only depend on state that the first run does not
+/// change, such as the files a loader reads. The work is done twice as well: a
+/// guarded `Network::load` parses the cfg and reads all weights in the child, then
+/// again here.
+///
+/// Only the first run is guarded. A fatal error that depends on state outside the
+/// process, e.g. a file replaced or deleted between the two runs, still ends the
+/// current process. Where the result is not needed, such as when validating a cfg,
+/// use [`check`] alone.
+pub fn call<F, T>(mut f: F) -> Result<T, GuardError>
+where
+    F: FnMut() -> T,
+{
+    check(&mut f)?;
+    Ok(f())
+}
+
+// Creates a pipe closed on `exec`. Otherwise a process that another thread spawns
+// meanwhile would inherit the write end and keep the reader from seeing EOF.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+pub(crate) fn pipe() -> io::Result<(c_int, c_int)> {
+    let mut fds = [0; 2];
+    if unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok((fds[0], fds[1]))
+}
+
+// Without `pipe2` the flag is set right after creation, leaving a short window.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+)))]
+pub(crate) fn pipe() -> io::Result<(c_int, c_int)> {
+    let mut fds = [0; 2];
+    if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    for &fd in &fds {
+        if unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) } != 0 {
+            let err = io::Error::last_os_error();
+            close(fds[0]);
+            close(fds[1]);
+            return Err(err);
+        }
+    }
+    Ok((fds[0], fds[1]))
+}
+
+pub(crate) fn close(fd: c_int) {
+    unsafe { libc::close(fd) };
+}
+
+pub(crate) fn wait(pid: libc::pid_t) -> io::Result<c_int> {
+    let mut status = 0;
+    loop {
+        if unsafe { libc::waitpid(pid, &mut status, 0) } >= 0 {
+            return Ok(status);
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fatal(result: Result<(), GuardError>) -> FatalError {
+        match result {
+            Err(GuardError::Fatal(err)) => err,
+            other => panic!("expected a fatal error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reports_exit_and_message() {
+        let err = fatal(check(|| unsafe {
+            let msg = b" 0 conv 16\nDarknet error location: ./src/parser.c, parse_network_cfg_custom(), line #1\nFirst section must be [net] or [network]: Success\n";
+            libc::write(libc::STDERR_FILENO, msg.as_ptr() as *const _, msg.len());
+            libc::exit(1)
+        }));
+        assert_eq!(err.termination, Termination::Exited(1));
+        assert!(err.output.starts_with(" 0 conv 16\n"));
+        assert_eq!(
+            err.message(),
+            "Darknet error location: ./src/parser.c, parse_network_cfg_custom(), line #1\nFirst section must be [net] or [network]: Success"
+        );
+    }
+
+    #[test]
+    fn reports_signals_and_panics() {
+        let err = fatal(check(|| unsafe { libc::abort() }));
+        assert_eq!(err.termination, Termination::Signaled(libc::SIGABRT));
+        let err = fatal(check(|| panic!("boom")));
+        assert_eq!(err.termination, Termination::Panicked);
+    }
+
+    #[test]
+    fn call_returns_the_in_process_result() {
+        let mut runs = 0;
+        let value = call(|| {
+            runs += 1;
+            runs
+        })
+        .unwrap();
+        // The child's increment is not visible here.
+        assert_eq!((value, runs), (1, 1));
+    }
+
+    #[test]
+    fn pipes_are_closed_on_exec() {
+        let (read, write) = pipe().unwrap();
+        for &fd in &[read, write] {
+            let flags = unsafe { libc::fcntl(fd, libc::F_GETFD) };
+            assert_ne!(flags & libc::FD_CLOEXEC, 0);
+            close(fd);
+        }
+    }
+
+    // Needs libdarknet built from source, see `crate::fixtures`.
+    #[cfg(not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs")))]
+    mod against_c {
+        use super::*;
+        use std::{env, ffi::CString, fs};
+
+        fn load(cfg: &str, name: &str) -> Result<(), GuardError> {
+            let path = env::temp_dir().join(format!("darknet-sys-guard-{}.cfg", name));
+            fs::write(&path, cfg).unwrap();
+            let path = CString::new(path.to_str().unwrap()).unwrap();
+            check(|| unsafe { crate::load_network(path.as_ptr() as *mut _, ptr::null_mut(), 0) })
+        }
+
+        #[test]
+        fn missing_net_section() {
+            let err = fatal(load("[convolutional]\nfilters=1\n", "no-net"));
+            assert!(err.message().contains("First section must be [net]"));
+        }
+
+        #[test]
+        fn missing_file() {
+            let path = CString::new("/nonexistent/darknet-sys.cfg").unwrap();
+            let err = fatal(check(|| unsafe {
+                crate::load_network(path.as_ptr() as *mut _, ptr::null_mut(), 0)
+            }));
+            assert!(err.output.contains("/nonexistent/darknet-sys.cfg"));
+        }
+
+        #[test]
+        fn valid_cfg() {
+            load(
+                "[net]\nwidth=16\nheight=16\nchannels=3\n\n[convolutional]\nfilters=2\nsize=3\npad=1\nactivation=leaky\n",
+                "valid",
+            )
+            .unwrap();
+        }
+    }
+}
@@ -1,5 +1,46 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
+#![cfg_attr(
+    feature = "dlopen",
+    allow(clippy::missing_safety_doc, clippy::too_many_arguments)
+)]
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+pub mod boxes;
+pub mod build_info;
+pub mod cfg;
+#[cfg(feature = "dlopen")]
+mod dlopen;
+mod enums;
+#[cfg(all(
+    test,
+    unix,
+    not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs"))
+))]
+mod fixtures;
+#[cfg(unix)]
+pub mod guard;
+pub mod metrics;
+pub mod nms;
+#[cfg(all(unix, feature = "log"))]
+pub mod output;
+#[cfg(any(feature = "safe", all(unix, not(feature = "dlopen"))))]
+mod path;
+#[cfg(all(unix, feature = "log"))]
+mod redirect;
+#[cfg(feature = "safe")]
+pub mod safe;
+#[cfg(all(unix, not(feature = "dlopen")))]
+pub mod train;
+pub mod weights;
+
+#[cfg(all(feature = "safe", feature = "dlopen"))]
+compile_error!("the `safe` feature calls libdarknet directly and cannot be combined with `dlopen`");
+
+#[cfg(feature = "dlopen")]
+pub use dlopen::{default_library_names, DlopenError};
+pub use enums::EnumError;
+#[cfg(any(feature = "safe", all(unix, not(feature = "dlopen"))))]
+pub use path::PathError;
@@ -0,0 +1,555 @@
+//! Mean average precision without libdarknet.
+//!
+//! [`Evaluator`] reproduces the metrics of `validate_detector_map` from darknet's
+//! `detector.c` over detections and ground-truth boxes collected elsewhere, e.g.
+//! from a converted model. `safe::Network::evaluate_map` uses it as well. Each
+//! class probability above zero of a [`Detection`] is ranked on its own, and it is
+//! a true positive if its IoU with a ground-truth box of the same class exceeds
+//! the threshold and no higher-ranked detection took that box already. AP is
+//! computed in `f64` like darknet, either as the area under the precision/recall
+//! curve or interpolated at a number of recall points.
+//!
+//! darknet drops probabilities up to 0.005 and runs NMS with a threshold of 0.45
+//! before evaluating; detections prepared the same way give the same results.
+//! Detections of equal probability may be ranked differently than by C's `qsort`,
+//! which is not stable.
+
+use crate::{boxes::BBox, nms::Detection};
+use std::cmp::Ordering;
+
+/// A ground-truth box, one line of a darknet label file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Truth {
+    pub class_id: usize,
+    pub bbox: BBox,
+}
+
+impl Truth {
+    /// Creates a ground-truth box.
+    pub fn new(class_id: usize, bbox: BBox) -> Self {
+        Self { class_id, bbox }
+    }
+}
+
+/// One point of a precision/recall curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrPoint {
+    /// Probability of the detection that adds this point.
+    pub prob: f32,
+    pub precision: f64,
+    pub recall: f64,
+}
+
+/// Results of one class.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassEvaluation {
+    /// Average precision.
+    pub ap: f64,
+    /// Ground-truth boxes of the class.
+    pub ground_truth: usize,
+    /// True positives above the confidence threshold.
+    pub true_positives: usize,
+    /// False positives above the confidence threshold.
+    pub false_positives: usize,
+    /// Ground-truth boxes of the class without a true positive.
+    pub false_negatives: usize,
+    /// IoU of the true positives of the class, divided by its true and false
+    /// positives like [`Evaluation::avg_iou`].
+    pub avg_iou: f32,
+    /// One point per detection of the class, by descending probability.
+    pub curve: Vec<PrPoint>,
+}
+
+/// Results of [`Evaluator::evaluate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation {
+    /// Mean of the per-class AP over all classes, including those without ground
+    /// truth. darknet returns it as `f32`.
+    pub map: f64,
+    pub classes: Vec<ClassEvaluation>,
+    /// Ranked detections, one per class probability above zero.
+    pub detections: usize,
+    pub ground_truth: usize,
+    /// Counts over all classes above the confidence threshold.
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+    /// IoU of the true positives above the confidence threshold, summed and divided
+    /// by the true and false positives as darknet does.
+    pub avg_iou: f32,
+}
+
+impl Evaluation {
+    /// `TP / (TP + FP)`, NaN without detections.
+    pub fn precision(&self) -> f32 {
+        let tp = self.true_positives as f32;
+        tp / (tp + self.false_positives as f32)
+    }
+
+    /// `TP / (TP + FN)`, NaN without ground truth.
+    pub fn recall(&self) -> f32 {
+        let tp = self.true_positives as f32;
+        tp / (tp + self.false_negatives as f32)
+    }
+
+    pub fn f1_score(&self) -> f32 {
+        let (precision, recall) = (self.precision(), self.recall());
+        2.0 * precision * recall / (precision + recall)
+    }
+}
+
+/// Detections and ground truth of a validation set, collected image by image.
+#[derive(Debug, Clone, Default)]
+pub struct Evaluator {
+    classes: usize,
+    images: Vec<ImageEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct ImageEntry {
+    candidates: Vec<Candidate>,
+    truths: Vec<Truth>,
+}
+
+// One class probability of a detection.
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    bbox: BBox,
+    class_id: usize,
+    prob: f32,
+}
+
+// A candidate with the global index of the ground-truth box it matched.
+#[derive(Debug, Clone, Copy)]
+struct Ranked {
+    prob: f32,
+    truth: Option<usize>,
+}
+
+impl Evaluator {
+    /// Creates an evaluator for `classes` classes.
+    pub fn new(classes: usize) -> Self {
+        Self {
+            classes,
+            images: Vec::new(),
+        }
+    }
+
+    pub fn classes(&self) -> usize {
+        self.classes
+    }
+
+    /// Number of images added.
+    pub fn len(&self) -> usize {
+        self.images.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// Adds the detections and ground-truth boxes of one image, with boxes in the
+    /// same coordinates, e.g. relative to the image size like label files.
+    ///
+    /// The order of `detections` matters for the counts at the confidence
+    /// threshold: darknet counts a detection as a false positive if an earlier one
+    /// of the same image matched the same box, whatever its probability.
+    ///
+    /// # Panics
+    ///
+    /// If a detection does not have [`Evaluator::classes`] probabilities or a
+    /// ground-truth box has a class out of range.
+    pub fn add_image(&mut self, detections: &[Detection], truths: &[Truth]) {
+        assert!(
+            detections.iter().all(|det| det.prob.len() == self.classes),
+            "all detections must have {} class probabilities",
+            self.classes
+        );
+        assert!(
+            truths.iter().all(|truth| truth.class_id < self.classes),
+            "ground-truth classes must be below {}",
+            self.classes
+        );
+        let candidates = detections
+            .iter()
+            .flat_map(|det| {
+                det.prob
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &prob)| prob > 0.0)
+                    .map(move |(class_id, &prob)| Candidate {
+                        bbox: det.bbox,
+                        class_id,
+                        prob,
+                    })
+            })
+            .collect();
+        self.images.push(ImageEntry {
+            candidates,
+            truths: truths.to_vec(),
+        });
+    }
+
+    /// Computes the metrics `validate_detector_map` prints for `thresh_calc_avg_iou
+    /// = thresh`, `iou_thresh` and `map_points`.
+    ///
+    /// A detection matches a ground-truth box if their IoU is greater than
+    /// `iou_thresh`. `map_points` is the number of recall points AP is interpolated
+    /// at, 11 for VOC2007 and 101 for COCO, or 0 for the area under the curve at
+    /// every unique recall as in VOC2010 and later. `thresh` only affects the counts
+    /// and the average IoU.
+    pub fn evaluate(&self, thresh: f32, iou_thresh: f32, map_points: usize) -> Evaluation {
+        let mut ranked = vec![Vec::new(); self.classes];
+        let mut truths_per_class = vec![0; self.classes];
+        let mut tp_per_class = vec![0; self.classes];
+        let mut fp_per_class = vec![0; self.classes];
+        let mut iou_sum = 0.0f32;
+        let mut iou_per_class = vec![0.0f32; self.classes];
+        let mut truth_offset = 0;
+        for image in &self.images {
+            for truth in &image.truths {
+                truths_per_class[truth.class_id] += 1;
+            }
+            let mut matched = Vec::with_capacity(image.candidates.len());
+            for candidate in &image.candidates {
+                let mut truth_index = None;
+                let mut max_iou = 0.0;
+                for (j, truth) in image.truths.iter().enumerate() {
+                    let iou = candidate.bbox.iou(&truth.bbox);
+                    if iou > iou_thresh && candidate.class_id == truth.class_id && iou > max_iou {
+                        max_iou = iou;
+                        truth_index = Some(truth_offset + j);
+                    }
+                }
+                if candidate.prob > thresh {
+                    if truth_index.is_some() && !matched.contains(&truth_index) {
+                        iou_sum += max_iou;
+                        iou_per_class[candidate.class_id] += max_iou;
+                        tp_per_class[candidate.class_id] += 1;
+                    } else {
+                        fp_per_class[candidate.class_id] += 1;
+                    }
+                }
+                matched.push(truth_index);
+                ranked[candidate.class_id].push(Ranked {
+                    prob: candidate.prob,
+                    truth: truth_index,
+                });
+            }
+            truth_offset += image.truths.len();
+        }
+
+        let mut taken = vec![false; truth_offset];
+        let classes: Vec<_> = ranked
+            .into_iter()
+            .enumerate()
+            .map(|(class_id, mut ranked)| {
+                let ground_truth = truths_per_class[class_id];
+                let curve = curve(&mut ranked, ground_truth, &mut taken);
+                let (tp, fp) = (tp_per_class[class_id], fp_per_class[class_id]);
+                ClassEvaluation {
+                    ap: average_precision(&curve, map_points),
+                    ground_truth,
+                    true_positives: tp,
+                    false_positives: fp,
+                    false_negatives: ground_truth - tp,
+                    avg_iou: average_iou(iou_per_class[class_id], tp + fp),
+                    curve,
+                }
+            })
+            .collect();
+
+        let true_positives: usize = tp_per_class.iter().sum();
+        let false_positives: usize = fp_per_class.iter().sum();
+        Evaluation {
+            map: classes.iter().map(|class| class.ap).sum::<f64>() / self.classes as f64,
+            detections: classes.iter().map(|class| class.curve.len()).sum(),
+            ground_truth: truth_offset,
+            true_positives,
+            false_positives,
+            false_negatives: truth_offset - true_positives,
+            avg_iou: average_iou(iou_sum, true_positives + false_positives),
+            classes,
+        }
+    }
+
+    /// COCO-style mAP@[.50:.95], the mean of [`Evaluation::map`] with 101 recall
+    /// points at the IoU thresholds 0.50, 0.55, ..., 0.95.
+    ///
+    /// Unlike the COCO API, a detection needs an IoU greater than the threshold to
+    /// match, as in darknet.
+    pub fn coco_map(&self) -> f64 {
+        (0..10)
+            .map(|k| self.evaluate(0.25, (50 + 5 * k) as f32 / 100.0, 101).map)
+            .sum::<f64>()
+            / 10.0
+    }
+}
+
+// Ranks the detections of one class by descending probability, marking each
+// ground-truth box taken by its first detection, and returns the curve.
+fn curve(ranked: &mut [Ranked], ground_truth: usize, taken: &mut [bool]) -> Vec<PrPoint> {
+    ranked.sort_by(|a, b| b.prob.partial_cmp(&a.prob).unwrap_or(Ordering::Equal));
+    let (mut tp, mut fp) = (0usize, 0usize);
+    ranked
+        .iter()
+        .map(|det| {
+            match det.truth {
+                Some(truth) if !taken[truth] => {
+                    taken[truth] = true;
+                    tp += 1;
+                }
+                _ => fp += 1,
+            }
+            PrPoint {
+                prob: det.prob,
+                precision: tp as f64 / (tp + fp) as f64,
+                recall: if ground_truth > 0 {
+                    tp as f64 / ground_truth as f64
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect()
+}
+
+fn average_iou(iou_sum: f32, positives: usize) -> f32 {
+    if positives > 0 {
+        iou_sum / positives as f32
+    } else {
+        0.0
+    }
+}
+
+fn average_precision(curve: &[PrPoint], map_points: usize) -> f64 {
+    if map_points == 0 {
+        let last = match curve.last() {
+            Some(last) => last,
+            None => return 0.0,
+        };
+        // Walks the curve backwards with the highest precision seen so far,
+        // including the precision of the point the recall step starts at.
+        let (mut last_recall, mut last_precision) = (last.recall, last.precision);
+        let mut ap = 0.0;
+        for point in curve.iter().rev().skip(1) {
+            let delta_recall = last_recall - point.recall;
+            last_recall = point.recall;
+            if point.precision > last_precision {
+                last_precision = point.precision;
+            }
+            ap += delta_recall * last_precision;
+        }
+        ap + last_recall * last_precision
+    } else {
+        let mut ap = 0.0;
+        for point in 0..map_points {
+            let recall = point as f64 / (map_points - 1) as f64;
+            ap += curve
+                .iter()
+                .filter(|p| p.recall >= recall)
+                .map(|p| p.precision)
+                .fold(0.0, f64::max);
+        }
+        ap / map_points as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Class 0: a hit, a duplicate of it, a miss and a second hit. Class 1 has a
+    // detection on a class 0 box but no ground truth.
+    fn evaluator() -> Evaluator {
+        let (a, b) = (
+            BBox::new(0.25, 0.25, 0.2, 0.2),
+            BBox::new(0.75, 0.75, 0.2, 0.2),
+        );
+        let mut evaluator = Evaluator::new(2);
+        evaluator.add_image(
+            &[
+                Detection::new(a, 0.9, vec![0.9, 0.0]),
+                Detection::new(a, 0.8, vec![0.8, 0.0]),
+                Detection::new(BBox::new(0.5, 0.9, 0.1, 0.1), 0.7, vec![0.7, 0.0]),
+                Detection::new(b, 0.6, vec![0.6, 0.0]),
+                Detection::new(b, 0.5, vec![0.0, 0.5]),
+            ],
+            &[Truth::new(0, a), Truth::new(0, b)],
+        );
+        evaluator
+    }
+
+    #[test]
+    fn computes_darknet_metrics() {
+        let evaluation = evaluator().evaluate(0.25, 0.5, 0);
+        let class = &evaluation.classes[0];
+        let precision: Vec<_> = class.curve.iter().map(|p| p.precision).collect();
+        let recall: Vec<_> = class.curve.iter().map(|p| p.recall).collect();
+        assert_eq!(precision, [1.0, 0.5, 1.0 / 3.0, 0.5]);
+        assert_eq!(recall, [0.5, 0.5, 0.5, 1.0]);
+        assert_eq!(class.ap, 0.75);
+        assert_eq!(evaluation.classes[1].ap, 0.0);
+        assert_eq!(evaluation.map, 0.375);
+        assert_eq!((evaluation.detections, evaluation.ground_truth), (5, 2));
+        assert_eq!(
+            (
+                evaluation.true_positives,
+                evaluation.false_positives,
+                evaluation.false_negatives
+            ),
+            (2, 3, 0)
+        );
+        assert!((evaluation.avg_iou - 0.4).abs() < 1e-6);
+        // The duplicate and the miss are false positives of class 0.
+        assert_eq!(
+            (
+                class.true_positives,
+                class.false_positives,
+                class.false_negatives
+            ),
+            (2, 2, 0)
+        );
+        assert!((class.avg_iou - 0.5).abs() < 1e-6);
+        let other = &evaluation.classes[1];
+        assert_eq!(
+            (
+                other.true_positives,
+                other.false_positives,
+                other.false_negatives
+            ),
+            (0, 1, 0)
+        );
+        assert_eq!(other.avg_iou, 0.0);
+    }
+
+    #[test]
+    fn counts_missed_boxes_per_class() {
+        let (a, b) = (
+            BBox::new(0.25, 0.25, 0.2, 0.2),
+            BBox::new(0.75, 0.75, 0.2, 0.2),
+        );
+        let mut evaluator = Evaluator::new(2);
+        evaluator.add_image(
+            &[Detection::new(a, 0.9, vec![0.9, 0.0])],
+            &[Truth::new(0, a), Truth::new(1, b), Truth::new(1, a)],
+        );
+        let evaluation = evaluator.evaluate(0.25, 0.5, 0);
+        let negatives: Vec<_> = evaluation
+            .classes
+            .iter()
+            .map(|class| class.false_negatives)
+            .collect();
+        assert_eq!(negatives, [0, 2]);
+        assert_eq!(evaluation.false_negatives, 2);
+        assert!((evaluation.classes[0].avg_iou - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn interpolates_at_recall_points() {
+        let evaluator = evaluator();
+        assert_eq!(evaluator.evaluate(0.25, 0.5, 11).classes[0].ap, 8.5 / 11.0);
+        assert_eq!(
+            evaluator.evaluate(0.25, 0.5, 101).classes[0].ap,
+            76.0 / 101.0
+        );
+        // The hits overlap exactly, so every IoU threshold gives the same AP.
+        assert!((evaluator.coco_map() - 38.0 / 101.0).abs() < 1e-12);
+    }
+
+    // Compares against `validate_detector_map`. Needs libdarknet built from source,
+    // see `crate::fixtures`.
+    #[cfg(all(
+        unix,
+        feature = "safe",
+        not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs"))
+    ))]
+    mod against_c {
+        use super::*;
+        use crate::{
+            do_nms_sort,
+            fixtures::{self, square, Dataset, IMAGE_SIZE, YOLO_CFG},
+            free_image, load_image_color,
+            safe::{MapOptions, Network},
+            validate_detector_map,
+        };
+        use std::{ffi::CString, path::Path};
+
+        // Images with a square of class 0 and, on some, a corner box of class 1.
+        fn fixture() -> Dataset {
+            let images: Vec<_> = [2, 6, 8, 12, 16]
+                .iter()
+                .enumerate()
+                .map(|(index, &offset)| {
+                    let mut truths = vec![square(0, offset)];
+                    if index % 2 == 0 {
+                        truths.push(Truth::new(1, BBox::new(0.875, 0.125, 0.25, 0.25)));
+                    }
+                    (offset, truths)
+                })
+                .collect();
+            fixtures::dataset("metrics", YOLO_CFG, &["square", "corner"], &images)
+        }
+
+        // Detections as `validate_detector_map` collects them: the image stretched
+        // to the input size, a threshold of 0.005 and NMS at 0.45.
+        fn detect(net: &mut Network, path: &Path) -> Vec<Detection> {
+            let path = CString::new(path.to_str().unwrap()).unwrap();
+            let image = unsafe { load_image_color(path.as_ptr() as *mut _, 0, 0) };
+            let size = IMAGE_SIZE as i32;
+            let sized = unsafe { crate::resize_image(image, size, size) };
+            let input =
+                unsafe { std::slice::from_raw_parts(sized.data, IMAGE_SIZE * IMAGE_SIZE * 3) }
+                    .to_vec();
+            unsafe {
+                free_image(image);
+                free_image(sized);
+            }
+            net.predict(&input).unwrap();
+            let mut dets = net.detections(1, 1, 0.005, 0.0, false, false).unwrap();
+            let n = dets.len() as i32;
+            unsafe { do_nms_sort(dets.as_mut_ptr(), n, 2, 0.45) };
+            dets.iter().map(Detection::from).collect()
+        }
+
+        #[test]
+        fn matches_validate_detector_map() {
+            let Dataset { data, cfg, images } = fixture();
+            let mut net = Network::load(&cfg, None::<&Path>, false).unwrap();
+            let mut evaluator = Evaluator::new(2);
+            for (image, truths) in &images {
+                let dets = detect(&mut net, image);
+                evaluator.add_image(&dets, truths);
+            }
+            for &(iou_thresh, map_points) in &[(0.5, 0), (0.5, 11), (0.25, 101)] {
+                let options = MapOptions {
+                    iou_thresh,
+                    map_points,
+                    ..MapOptions::default()
+                };
+                let rust = evaluator.evaluate(options.thresh, iou_thresh, map_points);
+                // darknet does not read the cfg and weights paths when given a network.
+                let (data_c, unused) = (
+                    CString::new(data.to_str().unwrap()).unwrap(),
+                    CString::default(),
+                );
+                let c = unsafe {
+                    validate_detector_map(
+                        data_c.as_ptr() as *mut _,
+                        unused.as_ptr() as *mut _,
+                        unused.as_ptr() as *mut _,
+                        options.thresh,
+                        iou_thresh,
+                        map_points as i32,
+                        net.raw().letter_box,
+                        net.as_ptr(),
+                    )
+                };
+                assert_eq!(rust.map as f32, c);
+                let report = net.evaluate_map(&data, &options).unwrap();
+                assert_eq!(report.names, ["square", "corner"]);
+                assert_eq!(report.evaluation, rust);
+            }
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! Non-maximum suppression without libdarknet.
+//!
+//! These functions reproduce `do_nms_sort`, `do_nms_obj` and `diounms_sort` from
+//! darknet's `box.c`. Like the originals they work in place: suppressed detections
+//! get their probabilities (and for [`nms_obj`] their objectness) set to zero, and
+//! the slice is reordered. Ties in score may be ordered differently than by C's
+//! `qsort`, which is not stable.
+
+use crate::{boxes::BBox, NMS_KIND};
+use std::cmp::Ordering;
+
+/// A detection as consumed by NMS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    pub bbox: BBox,
+    pub objectness: f32,
+    /// Per-class probabilities. All detections passed together must have the same
+    /// number of classes.
+    pub prob: Vec<f32>,
+}
+
+impl Detection {
+    /// Creates a detection.
+    pub fn new(bbox: BBox, objectness: f32, prob: Vec<f32>) -> Self {
+        Self {
+            bbox,
+            objectness,
+            prob,
+        }
+    }
+
+    /// Index and probability of the most probable class.
+    pub fn best_class(&self) -> Option<(usize, f32)> {
+        self.prob
+            .iter()
+            .copied()
+            .enumerate()
+            .fold(None, |best, (index, prob)| match best {
+                Some((_, best_prob)) if best_prob >= prob => best,
+                _ => Some((index, prob)),
+            })
+    }
+}
+
+#[cfg(feature = "safe")]
+impl From<crate::safe::Detection<'_>> for Detection {
+    fn from(from: crate::safe::Detection<'_>) -> Self {
+        Self::new(
+            from.bbox().into(),
+            from.objectness(),
+            from.probabilities().to_vec(),
+        )
+    }
+}
+
+/// Runs the NMS darknet selects for a layer's `nms_kind`: [`nms_sort`] for
+/// `DEFAULT_NMS`, [`diounms_sort`] otherwise.
+pub fn nms(dets: &mut [Detection], thresh: f32, nms_kind: NMS_KIND, beta1: f32) {
+    match nms_kind {
+        NMS_KIND::DEFAULT_NMS => nms_sort(dets, thresh),
+        _ => diounms_sort(dets, thresh, nms_kind, beta1),
+    }
+}
+
+/// Per-class greedy NMS by IoU, `do_nms_sort()`.
+pub fn nms_sort(dets: &mut [Detection], thresh: f32) {
+    let total = move_empty_to_back(dets);
+    let dets = &mut dets[..total];
+    for k in 0..classes(dets) {
+        sort_by_class(dets, Some(k));
+        for i in 0..dets.len() {
+            if dets[i].prob[k] == 0.0 {
+                continue;
+            }
+            let a = dets[i].bbox;
+            for det in &mut dets[i + 1..] {
+                if a.iou(&det.bbox) > thresh {
+                    det.prob[k] = 0.0;
+                }
+            }
+        }
+    }
+}
+
+/// Class-agnostic greedy NMS by objectness and IoU, `do_nms_obj()`.
+pub fn nms_obj(dets: &mut [Detection], thresh: f32) {
+    let total = move_empty_to_back(dets);
+    let dets = &mut dets[..total];
+    sort_by_class(dets, None);
+    for i in 0..dets.len() {
+        if dets[i].objectness == 0.0 {
+            continue;
+        }
+        let a = dets[i].bbox;
+        for det in &mut dets[i + 1..] {
+            if det.objectness == 0.0 {
+                continue;
+            }
+            if a.iou(&det.bbox) > thresh {
+                det.objectness = 0.0;
+                det.prob.iter_mut().for_each(|p| *p = 0.0);
+            }
+        }
+    }
+}
+
+/// Per-class NMS with the overlap measure selected by `nms_kind`, `diounms_sort()`.
+///
+/// `CORNERS_NMS` suppresses by IoU, `GREEDY_NMS` by DIoU, and `DIOU_NMS` by DIoU with
+/// the distance penalty raised to `beta1`. `DEFAULT_NMS` suppresses nothing, as in
+/// darknet.
+pub fn diounms_sort(dets: &mut [Detection], thresh: f32, nms_kind: NMS_KIND, beta1: f32) {
+    let total = move_empty_to_back(dets);
+    let dets = &mut dets[..total];
+    for k in 0..classes(dets) {
+        sort_by_class(dets, Some(k));
+        for i in 0..dets.len() {
+            if dets[i].prob[k] == 0.0 {
+                continue;
+            }
+            let a = dets[i].bbox;
+            for det in &mut dets[i + 1..] {
+                let b = &det.bbox;
+                let suppress = match nms_kind {
+                    NMS_KIND::CORNERS_NMS => a.iou(b) > thresh,
+                    NMS_KIND::GREEDY_NMS => a.diou(b) > thresh,
+                    NMS_KIND::DIOU_NMS => a.diou_with_beta(b, beta1) > thresh,
+                    _ => false,
+                };
+                if suppress {
+                    det.prob[k] = 0.0;
+                }
+            }
+        }
+    }
+}
+
+// Moves detections with zero objectness behind the others the way darknet does,
+// returning the number of remaining detections.
+fn move_empty_to_back(dets: &mut [Detection]) -> usize {
+    let mut k = dets.len();
+    let mut i = 0;
+    while i < k {
+        if dets[i].objectness == 0.0 {
+            k -= 1;
+            dets.swap(i, k);
+        } else {
+            i += 1;
+        }
+    }
+    k
+}
+
+fn classes(dets: &[Detection]) -> usize {
+    let classes = dets.first().map_or(0, |det| det.prob.len());
+    assert!(
+        dets.iter().all(|det| det.prob.len() == classes),
+        "all detections must have the same number of classes"
+    );
+    classes
+}
+
+// Descending by the probability of `class`, or by objectness, `nms_comparator_v3()`.
+// NaN scores, e.g. from a diverged network, go last so that the order stays total.
+fn sort_by_class(dets: &mut [Detection], class: Option<usize>) {
+    dets.sort_by(|a, b| {
+        let (a, b) = match class {
+            Some(k) => (a.prob[k], b.prob[k]),
+            None => (a.objectness, b.objectness),
+        };
+        match (a.is_nan(), b.is_nan()) {
+            (false, false) => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
+            (a_nan, b_nan) => a_nan.cmp(&b_nan),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nms_sort_suppresses_overlapping_lower_scores() {
+        let mut dets = vec![
+            Detection::new(BBox::new(0.5, 0.5, 0.2, 0.2), 0.9, vec![0.6, 0.1]),
+            Detection::new(BBox::new(0.51, 0.5, 0.2, 0.2), 0.9, vec![0.8, 0.05]),
+            Detection::new(BBox::new(0.1, 0.1, 0.1, 0.1), 0.9, vec![0.7, 0.0]),
+        ];
+        nms_sort(&mut dets, 0.45);
+        let probs: Vec<_> = dets.iter().map(|d| d.prob[0]).collect();
+        assert_eq!(probs.iter().filter(|&&p| p == 0.0).count(), 1);
+        assert!(dets.iter().any(|d| d.prob[0] == 0.8));
+        assert!(dets.iter().any(|d| d.prob[0] == 0.7));
+        // The second class is suppressed in the same box pair.
+        assert!(dets.iter().any(|d| d.prob[1] == 0.1));
+        assert!(dets.iter().all(|d| d.prob[1] != 0.05));
+    }
+
+    #[test]
+    fn nms_obj_zeroes_suppressed_detections() {
+        let mut dets = vec![
+            Detection::new(BBox::new(0.5, 0.5, 0.2, 0.2), 0.6, vec![0.6]),
+            Detection::new(BBox::new(0.5, 0.5, 0.2, 0.2), 0.8, vec![0.1]),
+        ];
+        nms_obj(&mut dets, 0.5);
+        assert_eq!(dets[0].objectness, 0.8);
+        assert_eq!(dets[1], Detection::new(dets[1].bbox, 0.0, vec![0.0]));
+    }
+
+    #[test]
+    fn sorts_nan_scores_last() {
+        let nan = Detection::new(BBox::new(0.5, 0.5, 0.2, 0.2), f32::NAN, vec![f32::NAN]);
+        let mut dets: Vec<_> = (0..40)
+            .map(|i| {
+                if i % 3 == 0 {
+                    nan.clone()
+                } else {
+                    let p = i as f32 / 40.0;
+                    Detection::new(BBox::new(p, 0.5, 0.01, 0.01), p, vec![p])
+                }
+            })
+            .collect();
+        nms_sort(&mut dets, 0.45);
+        let scores: Vec<_> = dets.iter().map(|d| d.prob[0]).collect();
+        let finite = scores.iter().take_while(|p| !p.is_nan()).count();
+        assert_eq!(finite, 26);
+        // The first NaN detection still suppresses the others at the same box.
+        assert!(scores[finite].is_nan());
+        assert!(scores[finite + 1..].iter().all(|&p| p == 0.0));
+        assert!(scores[..finite].windows(2).all(|w| w[0] >= w[1]));
+
+        nms_obj(&mut dets, 0.45);
+        assert!(dets[..26].iter().all(|d| !d.objectness.is_nan()));
+    }
+
+    // Compares against libdarknet built from source, see `crate::fixtures`.
+    #[cfg(not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs")))]
+    mod against_c {
+        use super::*;
+        use crate::{box_, detection};
+
+        // Small xorshift generator, so the tests need no extra dependencies.
+        struct Rng(u64);
+
+        impl Rng {
+            fn next_f32(&mut self) -> f32 {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                (self.0 >> 40) as f32 / (1u64 << 24) as f32
+            }
+        }
+
+        fn random_detections(rng: &mut Rng, n: usize, classes: usize) -> Vec<Detection> {
+            (0..n)
+                .map(|_| {
+                    let bbox = BBox::new(
+                        rng.next_f32(),
+                        rng.next_f32(),
+                        0.05 + rng.next_f32() * 0.3,
+                        0.05 + rng.next_f32() * 0.3,
+                    );
+                    // Some detections without objectness, and some zero probabilities.
+                    let objectness = if rng.next_f32() < 0.1 {
+                        0.0
+                    } else {
+                        rng.next_f32()
+                    };
+                    let prob = (0..classes)
+                        .map(|_| {
+                            let p = rng.next_f32();
+                            if p < 0.2 {
+                                0.0
+                            } else {
+                                p * objectness
+                            }
+                        })
+                        .collect();
+                    Detection::new(bbox, objectness, prob)
+                })
+                .collect()
+        }
+
+        fn run_c(dets: &[Detection], f: impl FnOnce(*mut detection, i32, i32)) -> Vec<Detection> {
+            let classes = dets[0].prob.len();
+            let mut probs: Vec<Vec<f32>> = dets.iter().map(|d| d.prob.clone()).collect();
+            let mut raw: Vec<detection> = dets
+                .iter()
+                .zip(probs.iter_mut())
+                .map(|(d, prob)| {
+                    let mut raw: detection = unsafe { std::mem::zeroed() };
+                    raw.bbox = box_::from(d.bbox);
+                    raw.classes = classes as i32;
+                    raw.objectness = d.objectness;
+                    raw.prob = prob.as_mut_ptr();
+                    raw
+                })
+                .collect();
+            f(raw.as_mut_ptr(), raw.len() as i32, classes as i32);
+            raw.iter()
+                .map(|raw| {
+                    let prob = unsafe { std::slice::from_raw_parts(raw.prob, classes) };
+                    Detection::new(raw.bbox.into(), raw.objectness, prob.to_vec())
+                })
+                .collect()
+        }
+
+        // Boxes are random, so each one identifies its detection.
+        fn assert_same(rust: &[Detection], c: &[Detection]) {
+            assert_eq!(rust.len(), c.len());
+            for det in c {
+                let matching = rust
+                    .iter()
+                    .find(|r| r.bbox == det.bbox)
+                    .expect("detection missing from Rust output");
+                assert_eq!(matching, det);
+            }
+        }
+
+        #[test]
+        fn matches_do_nms_sort() {
+            let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+            for _ in 0..50 {
+                let dets = random_detections(&mut rng, 60, 4);
+                let c = run_c(&dets, |d, n, classes| unsafe {
+                    crate::do_nms_sort(d, n, classes, 0.45)
+                });
+                let mut rust = dets.clone();
+                nms_sort(&mut rust, 0.45);
+                assert_same(&rust, &c);
+            }
+        }
+
+        #[test]
+        fn matches_do_nms_obj() {
+            let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+            for _ in 0..50 {
+                let dets = random_detections(&mut rng, 60, 4);
+                let c = run_c(&dets, |d, n, classes| unsafe {
+                    crate::do_nms_obj(d, n, classes, 0.45)
+                });
+                let mut rust = dets.clone();
+                nms_obj(&mut rust, 0.45);
+                assert_same(&rust, &c);
+            }
+        }
+
+        #[test]
+        fn matches_diounms_sort() {
+            let mut rng = Rng(0xdead_beef_cafe_f00d);
+            for &kind in &[
+                NMS_KIND::GREEDY_NMS,
+                NMS_KIND::DIOU_NMS,
+                NMS_KIND::CORNERS_NMS,
+            ] {
+                for _ in 0..50 {
+                    let dets = random_detections(&mut rng, 60, 4);
+                    let c = run_c(&dets, |d, n, classes| unsafe {
+                        crate::diounms_sort(d, n, classes, 0.45, kind, 0.6)
+                    });
+                    let mut rust = dets.clone();
+                    diounms_sort(&mut rust, 0.45, kind, 0.6);
+                    assert_same(&rust, &c);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Forwarding of libdarknet's console output to the [`log`] crate.
+//!
+//! libdarknet reports progress, layer tables, warnings and training loss with
+//! `printf` and `fprintf`. [`capture`] redirects the stdout and stderr file
+//! descriptors into pipes while a closure runs and logs each line with target
+//! `darknet`, at a level guessed from its content (see [`line_level`]).
+//!
+//! The descriptors belong to the whole process, so anything other threads write to
+//! stdout or stderr during a capture is logged as well, and so is what a logger
+//! prints to them. Loggers printing to the console should write through [`stdout`]
+//! or [`stderr`] instead, which bypass captures, e.g. with `env_logger`:
+//!
+//! ```ignore
+//! env_logger::Builder::from_default_env()
+//!     .target(env_logger::Target::Pipe(Box::new(darknet_sys::output::stderr())))
+//!     .init();
+//! ```
+//!
+//! A captured line that ends with a line just logged is taken for the logger's own
+//! output and printed as is rather than logged again. Captures are serialized; a
+//! nested capture on the same thread runs its closure without capturing again.
+//! `tracing` users can receive the events through `tracing_log::LogTracer`.
+
+use crate::redirect::{self, Redirects};
+use log::Level;
+use std::{
+    io::{self, Write},
+    os::raw::c_int,
+};
+
+/// Target of the log records.
+pub const TARGET: &str = "darknet";
+
+/// Runs `f` with stdout and stderr forwarded to the logger.
+///
+/// All lines are logged before this returns, also when `f` panics. Errors only
+/// come from setting up the redirection, in which case `f` is not run.
+pub fn capture<F, R>(f: F) -> io::Result<R>
+where
+    F: FnOnce() -> R,
+{
+    redirect::with_lock(|nested| {
+        if nested {
+            return Ok(f());
+        }
+        let _redirects = Redirects::new(
+            |line, stderr| log::log!(target: TARGET, line_level(line, stderr), "{}", line),
+        )?;
+        Ok(f())
+    })
+}
+
+/// Returns a writer to the process's stdout that is not captured.
+pub fn stdout() -> Console {
+    Console(libc::STDOUT_FILENO)
+}
+
+/// Returns a writer to the process's stderr that is not captured.
+pub fn stderr() -> Console {
+    Console(libc::STDERR_FILENO)
+}
+
+/// Writer returned by [`stdout`] and [`stderr`].
+///
+/// During a [`capture`] it writes to the descriptor that was in place before the
+/// capture started, otherwise to the descriptor itself. It is unbuffered.
+#[derive(Debug, Clone, Copy)]
+pub struct Console(c_int);
+
+impl Write for Console {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        redirect::write_original(self.0, buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Level darknet's output line `line` is logged at.
+///
+/// Lines mentioning errors, or files that could not be opened, are errors; lines
+/// mentioning warnings are warnings. Everything else is info on stdout and debug
+/// on stderr, where darknet prints its layer tables and progress.
+pub fn line_level(line: &str, stderr: bool) -> Level {
+    let lower = line.to_ascii_lowercase();
+    if lower.contains("error") || lower.starts_with("couldn't") || lower.starts_with("can't") {
+        Level::Error
+    } else if lower.contains("warning") {
+        Level::Warn
+    } else if stderr {
+        Level::Debug
+    } else {
+        Level::Info
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::{Log, Metadata, Record};
+    use std::sync::Mutex;
+
+    // Collects records and, like `env_logger` set up as in the module docs, also
+    // prints them to stderr.
+    struct Collect(Mutex<Vec<(Level, String)>>);
+
+    impl Log for Collect {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            if record.target() == TARGET {
+                let line = record.args().to_string();
+                let printed = format!("[{} darknet] {}\n", record.level(), line);
+                stderr().write_all(printed.as_bytes()).unwrap();
+                self.0.lock().unwrap().push((record.level(), line));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: Collect = Collect(Mutex::new(Vec::new()));
+
+    #[test]
+    fn logs_lines_written_to_stdout_and_stderr() {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+        let value = capture(|| unsafe {
+            let out = b"net.optimized_memory = 0 \n\nmini_batch = 1\0";
+            libc::printf(out.as_ptr() as *const _);
+            let err = b"Warning: width=416 is not divisible by 32\nCouldn't open file: x.cfg\n";
+            libc::write(libc::STDERR_FILENO, err.as_ptr() as *const _, err.len());
+            7
+        })
+        .unwrap();
+        assert_eq!(value, 7);
+        // The test harness may print to stdout meanwhile, so only look for our lines.
+        let lines = LOGGER.0.lock().unwrap();
+        for expected in &[
+            (Level::Info, "net.optimized_memory = 0"),
+            (Level::Info, "mini_batch = 1"),
+            (Level::Warn, "Warning: width=416 is not divisible by 32"),
+            (Level::Error, "Couldn't open file: x.cfg"),
+        ] {
+            let count = lines
+                .iter()
+                .filter(|(level, line)| (*level, &line[..]) == *expected)
+                .count();
+            assert_eq!(count, 1, "{:?} logged {} times", expected, count);
+        }
+        assert!(lines.iter().all(|(_, line)| !line.is_empty()));
+        // What the logger printed to stderr was not captured and logged again.
+        assert!(lines.iter().all(|(_, line)| !line.contains("darknet]")));
+    }
+
+    #[test]
+    fn guesses_levels() {
+        assert_eq!(
+            line_level(" CUDA Error: out of memory", false),
+            Level::Error
+        );
+        assert_eq!(line_level("can't open file", true), Level::Error);
+        assert_eq!(line_level(" 0 conv 32 3 x 3/ 1", true), Level::Debug);
+        assert_eq!(line_level("Total BFLOPS 59.563", false), Level::Info);
+    }
+}
@@ -0,0 +1,73 @@
+use std::{
+    error,
+    ffi::CString,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// A path that cannot be passed to darknet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    /// The path cannot be passed to darknet, e.g. it contains a NUL byte.
+    Invalid(PathBuf),
+    /// The file does not exist or is not a regular file.
+    NotFound(PathBuf),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Invalid(path) => write!(f, "invalid path {}", path.display()),
+            PathError::NotFound(path) => write!(f, "file not found: {}", path.display()),
+        }
+    }
+}
+
+impl error::Error for PathError {}
+
+/// Converts `path` into a C string suitable for darknet's `char *` parameters.
+pub(crate) fn path_to_cstring(path: &Path) -> Result<CString, PathError> {
+    #[cfg(unix)]
+    let bytes = {
+        use std::os::unix::ffi::OsStrExt;
+        path.as_os_str().as_bytes().to_vec()
+    };
+    #[cfg(not(unix))]
+    let bytes = path
+        .to_str()
+        .ok_or_else(|| PathError::Invalid(path.to_owned()))?
+        .as_bytes()
+        .to_vec();
+    CString::new(bytes).map_err(|_| PathError::Invalid(path.to_owned()))
+}
+
+/// Like [`path_to_cstring`], but fails early if the file does not exist, since
+/// darknet terminates the process on missing files.
+pub(crate) fn existing_path_to_cstring(path: &Path) -> Result<CString, PathError> {
+    if !path.is_file() {
+        return Err(PathError::NotFound(path.to_owned()));
+    }
+    path_to_cstring(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_nul_bytes_and_missing_files() {
+        assert_eq!(
+            path_to_cstring(Path::new("yolo\0.cfg")),
+            Err(PathError::Invalid(PathBuf::from("yolo\0.cfg")))
+        );
+        assert_eq!(
+            existing_path_to_cstring(Path::new("/nonexistent/yolo.cfg")),
+            Err(PathError::NotFound(PathBuf::from("/nonexistent/yolo.cfg")))
+        );
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        assert_eq!(
+            existing_path_to_cstring(&manifest).unwrap().as_bytes(),
+            manifest.to_str().unwrap().as_bytes()
+        );
+    }
+}
@@ -0,0 +1,239 @@
+// Redirection of the stdout and stderr file descriptors into pipes while libdarknet
+// prints, used to log its output.
+
+use crate::guard;
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    os::{raw::c_int, unix::io::FromRawFd},
+    ptr,
+    sync::{Arc, Mutex, RwLock},
+    thread::{self, JoinHandle},
+};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    static HOLDING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Runs `f` holding the process-wide lock on redirection, since the descriptors
+// belong to the whole process. A nested call on the thread holding the lock runs
+// `f` directly and passes `true`.
+pub(crate) fn with_lock<F, R>(f: F) -> R
+where
+    F: FnOnce(bool) -> R,
+{
+    if HOLDING.with(Cell::get) {
+        return f(true);
+    }
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    HOLDING.with(|holding| holding.set(true));
+    let _release = Release;
+    f(false)
+}
+
+struct Release;
+
+impl Drop for Release {
+    fn drop(&mut self) {
+        HOLDING.with(|holding| holding.set(false));
+    }
+}
+
+// The original descriptor of each redirected one, while redirected.
+static SAVED: RwLock<Vec<(c_int, c_int)>> = RwLock::new(Vec::new());
+
+// Writes to the original descriptor of `fd` while it is redirected, and to `fd`
+// otherwise.
+pub(crate) fn write_original(fd: c_int, buf: &[u8]) -> io::Result<usize> {
+    // Holding the lock keeps the original descriptor open.
+    let saved = SAVED.read().unwrap_or_else(|err| err.into_inner());
+    let target = saved
+        .iter()
+        .find(|&&(redirected, _)| redirected == fd)
+        .map_or(fd, |&(_, original)| original);
+    let written = unsafe { libc::write(target, buf.as_ptr() as *const _, buf.len()) };
+    if written < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(written as usize)
+    }
+}
+
+// stdout and stderr pointed at pipes until dropped. Each non-blank line is passed
+// to `on_line` with whether it came from stderr; all of them are before `drop`
+// returns.
+//
+// What `on_line` writes to stdout or stderr is captured like anything else, so it
+// should write through `write_original` instead. A line ending with one of the
+// last lines passed to `on_line`, as a logger printing to stderr would produce, is
+// written to the original descriptor rather than passed on again.
+pub(crate) struct Redirects {
+    targets: Vec<Target>,
+    readers: Vec<JoinHandle<()>>,
+}
+
+impl Redirects {
+    pub(crate) fn new<F>(on_line: F) -> io::Result<Self>
+    where
+        F: Fn(&str, bool) + Send + Sync + 'static,
+    {
+        flush_all();
+        let on_line = Arc::new(on_line);
+        let recent = Arc::new(Recent::default());
+        // Dropping a partial `Redirects` undoes the redirections made so far.
+        let mut redirects = Self {
+            targets: Vec::with_capacity(2),
+            readers: Vec::with_capacity(2),
+        };
+        for &(fd, stderr) in &[(libc::STDOUT_FILENO, false), (libc::STDERR_FILENO, true)] {
+            let (file, target) = Target::new(fd)?;
+            SAVED
+                .write()
+                .unwrap_or_else(|err| err.into_inner())
+                .push((fd, target.saved));
+            redirects.targets.push(target);
+            let on_line = Arc::clone(&on_line);
+            let recent = Arc::clone(&recent);
+            redirects.readers.push(thread::spawn(move || {
+                read_lines(file, |line| {
+                    if recent.is_echo(line) {
+                        let _ = write_original(fd, format!("{}\n", line).as_bytes());
+                    } else {
+                        on_line(line, stderr);
+                    }
+                })
+            }));
+        }
+        Ok(redirects)
+    }
+}
+
+impl Drop for Redirects {
+    fn drop(&mut self) {
+        flush_all();
+        for target in &self.targets {
+            target.restore();
+        }
+        // The readers see EOF now that no write end is left.
+        for reader in self.readers.drain(..) {
+            let _ = reader.join();
+        }
+        let mut saved = SAVED.write().unwrap_or_else(|err| err.into_inner());
+        saved.clear();
+        for target in self.targets.drain(..) {
+            unsafe { libc::close(target.saved) };
+        }
+    }
+}
+
+// The last lines passed on, shared by the readers of stdout and stderr.
+#[derive(Default)]
+struct Recent(Mutex<VecDeque<String>>);
+
+impl Recent {
+    const LEN: usize = 8;
+
+    // Whether `line` ends with, and is longer than, one of the recent lines.
+    // Otherwise `line` becomes the most recent one.
+    fn is_echo(&self, line: &str) -> bool {
+        let mut recent = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        if recent
+            .iter()
+            .any(|previous| line.len() > previous.len() && line.ends_with(previous.as_str()))
+        {
+            return true;
+        }
+        if recent.len() == Self::LEN {
+            recent.pop_front();
+        }
+        recent.push_back(line.to_owned());
+        false
+    }
+}
+
+// One descriptor pointed at the write end of a pipe.
+struct Target {
+    fd: c_int,
+    // The original descriptor, closed once nothing writes to it any more.
+    saved: c_int,
+    write: c_int,
+}
+
+impl Target {
+    // Returns the read end of the pipe along with the target.
+    fn new(fd: c_int) -> io::Result<(File, Self)> {
+        let (read, write) = guard::pipe()?;
+        let saved = unsafe { libc::fcntl(fd, libc::F_DUPFD_CLOEXEC, 0) };
+        if saved < 0 || unsafe { libc::dup2(write, fd) } < 0 {
+            let err = io::Error::last_os_error();
+            unsafe {
+                if saved >= 0 {
+                    libc::close(saved);
+                }
+                libc::close(read);
+                libc::close(write);
+            }
+            return Err(err);
+        }
+        let file = unsafe { File::from_raw_fd(read) };
+        Ok((file, Self { fd, saved, write }))
+    }
+
+    // Points `fd` back at the original descriptor and closes the pipe's write end.
+    fn restore(&self) {
+        unsafe {
+            libc::dup2(self.saved, self.fd);
+            libc::close(self.write);
+        }
+    }
+}
+
+// Flushes C stdio, which fully buffers output to pipes, and Rust's own streams.
+fn flush_all() {
+    unsafe { libc::fflush(ptr::null_mut()) };
+    let _ = io::stdout().flush();
+    let _ = io::stderr().flush();
+}
+
+fn read_lines<F: Fn(&str)>(file: File, on_line: F) {
+    let mut reader = BufReader::new(file);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        let text = String::from_utf8_lossy(&buf);
+        // Progress bars rewrite the line with `\r`; pass on each state.
+        for line in text.split('\r') {
+            let line = line.trim_end();
+            if !line.trim().is_empty() {
+                on_line(line);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_logged_lines_printed_again() {
+        let recent = Recent::default();
+        assert!(!recent.is_echo("mini_batch = 1"));
+        assert!(recent.is_echo("[INFO  darknet] mini_batch = 1"));
+        // Repeated lines, and lines only starting with a recent one, are new.
+        assert!(!recent.is_echo("mini_batch = 1"));
+        assert!(!recent.is_echo("mini_batch = 16"));
+        for index in 0..Recent::LEN {
+            assert!(!recent.is_echo(&format!("line {}", index)));
+        }
+        assert!(!recent.is_echo("[INFO  darknet] mini_batch = 1"));
+    }
+}
@@ -0,0 +1,162 @@
+use super::{to_c_int, Detections, Error, Image, Network, Result};
+use crate::{box_, free_batch_detections, image, network_predict_batch};
+use std::ptr;
+
+impl Network {
+    /// Detects objects in several images with one forward pass of
+    /// `network_predict_batch`.
+    ///
+    /// Between 1 and [`Network::batch`] images are accepted, so the network must be
+    /// loaded with [`Network::load_with_batch`] for more than one. Each image is
+    /// letterboxed with `letterbox` set, or else stretched, to the input size.
+    /// Returns one [`Detections`] per image, in order, with boxes relative to that
+    /// image's size with `relative` set, or else in its pixels.
+    pub fn predict_batch(
+        &mut self,
+        images: &[Image],
+        thresh: f32,
+        hier_thresh: f32,
+        relative: bool,
+        letterbox: bool,
+    ) -> Result<Vec<Detections>> {
+        let batch = self.batch();
+        if images.is_empty() || images.len() > batch {
+            return Err(Error::InvalidArgument(format!(
+                "expected 1 to {} images, got {}",
+                batch,
+                images.len()
+            )));
+        }
+        let (width, height, channels) = (self.width(), self.height(), self.channels());
+        let inputs = self.inputs();
+        // Batch items without an image stay zero.
+        let mut input = vec![0.0; batch * inputs];
+        for (image, item) in images.iter().zip(input.chunks_mut(inputs)) {
+            if image.channels() != channels {
+                return Err(Error::InvalidArgument(format!(
+                    "expected images with {} channels, got {}",
+                    channels,
+                    image.channels()
+                )));
+            }
+            let sized = if letterbox {
+                image.letterbox(width, height)?
+            } else {
+                image.resize(width, height)?
+            };
+            item.copy_from_slice(sized.data());
+        }
+
+        let (w, h) = (to_c_int(width)?, to_c_int(height)?);
+        let count = to_c_int(images.len())?;
+        // darknet only reads the input data.
+        let input = image {
+            w,
+            h,
+            c: to_c_int(channels)?,
+            data: input.as_mut_ptr(),
+        };
+        // darknet corrects the boxes of all images for a single image size. Ask for
+        // boxes relative to the network input, which it leaves unchanged, and map
+        // them to each image below.
+        let pairs = unsafe {
+            network_predict_batch(
+                self.as_ptr(),
+                input,
+                count,
+                w,
+                h,
+                thresh,
+                hier_thresh,
+                ptr::null_mut(),
+                1,
+                0,
+            )
+        };
+        if pairs.is_null() {
+            return Err(Error::NullPointer("network_predict_batch"));
+        }
+        let mask_len = self.mask_len();
+        let detections = images
+            .iter()
+            .enumerate()
+            .map(|(index, image)| {
+                let pair = unsafe { &mut *pairs.add(index) };
+                let mut dets =
+                    unsafe { Detections::from_raw(pair.dets, pair.num.max(0) as usize, mask_len) };
+                // `dets` owns them now, so free_batch_detections only frees the array.
+                pair.dets = ptr::null_mut();
+                pair.num = 0;
+                for det in dets.raw_mut() {
+                    det.bbox = to_image_box(
+                        det.bbox,
+                        (width, height),
+                        (image.width(), image.height()),
+                        relative,
+                        letterbox,
+                    );
+                }
+                dets
+            })
+            .collect();
+        unsafe { free_batch_detections(pairs, count) };
+        Ok(detections)
+    }
+}
+
+// Maps a box relative to the network input to an image of `image` size, undoing
+// the padding of `letterbox_image` like darknet's `correct_yolo_boxes`.
+fn to_image_box(
+    bbox: box_,
+    (net_w, net_h): (usize, usize),
+    (image_w, image_h): (usize, usize),
+    relative: bool,
+    letterbox: bool,
+) -> box_ {
+    let mut bbox = bbox;
+    if letterbox && image_w > 0 && image_h > 0 {
+        let (new_w, new_h) = if (net_w as f32 / image_w as f32) < (net_h as f32 / image_h as f32) {
+            (net_w, image_h * net_w / image_w)
+        } else {
+            (image_w * net_h / image_h, net_h)
+        };
+        let (scale_w, scale_h) = (new_w as f32 / net_w as f32, new_h as f32 / net_h as f32);
+        bbox.x = (bbox.x - (net_w - new_w) as f32 / 2.0 / net_w as f32) / scale_w;
+        bbox.y = (bbox.y - (net_h - new_h) as f32 / 2.0 / net_h as f32) / scale_h;
+        bbox.w /= scale_w;
+        bbox.h /= scale_h;
+    }
+    if !relative {
+        bbox.x *= image_w as f32;
+        bbox.w *= image_w as f32;
+        bbox.y *= image_h as f32;
+        bbox.h *= image_h as f32;
+    }
+    bbox
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undoes_letterbox_padding() {
+        // A 200x100 image letterboxed into 100x100 fills rows 25 to 75.
+        let bbox = box_ {
+            x: 0.5,
+            y: 0.25,
+            w: 0.5,
+            h: 0.5,
+        };
+        let mapped = to_image_box(bbox, (100, 100), (200, 100), false, true);
+        assert_eq!(
+            (mapped.x, mapped.y, mapped.w, mapped.h),
+            (100.0, 0.0, 100.0, 100.0)
+        );
+        let stretched = to_image_box(bbox, (100, 100), (200, 100), true, false);
+        assert_eq!(
+            (stretched.x, stretched.y, stretched.w, stretched.h),
+            (0.5, 0.25, 0.5, 0.5)
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use super::{to_c_int, Error, Network, Result};
+use crate::{box_, detection, free_detections, get_network_boxes, LAYER_TYPE};
+use std::{os::raw::c_int, ptr, slice};
+
+/// Detections allocated by darknet, freed with `free_detections` on drop.
+#[derive(Debug)]
+pub struct Detections {
+    dets: *mut detection,
+    len: usize,
+    mask_len: usize,
+}
+
+impl Detections {
+    /// Takes ownership of `len` detections allocated by darknet.
+    ///
+    /// `mask_len` is the length of each `mask` buffer, `coords - 4` of the layer that
+    /// produced the detections, or 0 if it has no masks.
+    ///
+    /// # Safety
+    ///
+    /// `dets` must come from `get_network_boxes` or `make_network_boxes` with
+    /// exactly `len` entries, and must not be freed elsewhere.
+    pub unsafe fn from_raw(dets: *mut detection, len: usize, mask_len: usize) -> Self {
+        Self {
+            dets,
+            len: if dets.is_null() { 0 } else { len },
+            mask_len,
+        }
+    }
+
+    /// Number of detections.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no detections.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the detection at `index`.
+    pub fn get(&self, index: usize) -> Option<Detection<'_>> {
+        self.raw().get(index).map(|raw| Detection {
+            raw,
+            mask_len: self.mask_len,
+        })
+    }
+
+    /// Iterates over all detections, including those whose probabilities were
+    /// zeroed by NMS.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.raw().iter(),
+            mask_len: self.mask_len,
+        }
+    }
+
+    /// The underlying `detection` structs.
+    pub fn raw(&self) -> &[detection] {
+        if self.dets.is_null() {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(self.dets, self.len) }
+    }
+
+    pub(crate) fn raw_mut(&mut self) -> &mut [detection] {
+        if self.dets.is_null() {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.dets, self.len) }
+    }
+
+    /// Pointer to the first detection, e.g. for darknet's in-place NMS functions.
+    /// It stays owned by `self`.
+    pub fn as_mut_ptr(&mut self) -> *mut detection {
+        self.dets
+    }
+}
+
+impl Drop for Detections {
+    fn drop(&mut self) {
+        if !self.dets.is_null() {
+            unsafe { free_detections(self.dets, self.len as c_int) };
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Detections {
+    type Item = Detection<'a>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over [`Detections`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, detection>,
+    mask_len: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Detection<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mask_len = self.mask_len;
+        self.inner.next().map(|raw| Detection { raw, mask_len })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mask_len = self.mask_len;
+        self.inner
+            .next_back()
+            .map(|raw| Detection { raw, mask_len })
+    }
+}
+
+/// A borrowed view of one darknet `detection`.
+#[derive(Debug, Clone, Copy)]
+pub struct Detection<'a> {
+    raw: &'a detection,
+    mask_len: usize,
+}
+
+impl<'a> Detection<'a> {
+    /// Bounding box as center `x`, `y` and size `w`, `h`.
+    pub fn bbox(&self) -> box_ {
+        self.raw.bbox
+    }
+
+    /// Objectness score.
+    pub fn objectness(&self) -> f32 {
+        self.raw.objectness
+    }
+
+    /// Per-class probabilities, `classes` values.
+    pub fn probabilities(&self) -> &'a [f32] {
+        unsafe { slice_or_empty(self.raw.prob, self.raw.classes) }
+    }
+
+    /// Index and probability of the most probable class, or `None` if there are no
+    /// classes.
+    ///
+    /// Ties go to the lower index. NaN probabilities rank below all others.
+    pub fn best_class(&self) -> Option<(usize, f32)> {
+        self.probabilities().iter().copied().enumerate().fold(
+            None,
+            |best, (index, prob)| match best {
+                Some((_, best_prob)) if best_prob >= prob || prob.is_nan() => best,
+                _ => Some((index, prob)),
+            },
+        )
+    }
+
+    /// Mask coefficients of region layers with `coords > 4`.
+    pub fn mask(&self) -> Option<&'a [f32]> {
+        non_empty(unsafe { slice_or_empty(self.raw.mask, self.mask_len as c_int) })
+    }
+
+    /// Uncertainty of `x`, `y`, `w` and `h` from Gaussian YOLO layers.
+    pub fn uncertainty(&self) -> Option<&'a [f32; 4]> {
+        if self.raw.uc.is_null() {
+            None
+        } else {
+            Some(unsafe { &*(self.raw.uc as *const [f32; 4]) })
+        }
+    }
+
+    /// Embedding vector, `embedding_size` values, for layers with `embedding_layer`.
+    pub fn embedding(&self) -> Option<&'a [f32]> {
+        non_empty(unsafe { slice_or_empty(self.raw.embeddings, self.raw.embedding_size) })
+    }
+
+    /// Track id assigned by `set_track_id`, or 0.
+    pub fn track_id(&self) -> i32 {
+        self.raw.track_id
+    }
+
+    /// The underlying `detection` struct.
+    pub fn raw(&self) -> &'a detection {
+        self.raw
+    }
+}
+
+unsafe fn slice_or_empty<'a>(data: *const f32, len: c_int) -> &'a [f32] {
+    if data.is_null() || len <= 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len as usize)
+    }
+}
+
+fn non_empty(slice: &[f32]) -> Option<&[f32]> {
+    if slice.is_empty() {
+        None
+    } else {
+        Some(slice)
+    }
+}
+
+impl Network {
+    /// Collects the detections of the last forward pass with `get_network_boxes`.
+    ///
+    /// `width` and `height` are the size of the original image. With `relative` the
+    /// boxes are relative to the image size, otherwise in pixels. `letterbox` must
+    /// match how the input image was resized.
+    pub fn detections(
+        &self,
+        width: usize,
+        height: usize,
+        thresh: f32,
+        hier_thresh: f32,
+        relative: bool,
+        letterbox: bool,
+    ) -> Result<Detections> {
+        let (w, h) = (to_c_int(width)?, to_c_int(height)?);
+        let mut num: c_int = 0;
+        let dets = unsafe {
+            get_network_boxes(
+                self.as_ptr(),
+                w,
+                h,
+                thresh,
+                hier_thresh,
+                ptr::null_mut(),
+                relative as c_int,
+                &mut num,
+                letterbox as c_int,
+            )
+        };
+        if dets.is_null() {
+            return Err(Error::NullPointer("get_network_boxes"));
+        }
+        Ok(unsafe { Detections::from_raw(dets, num.max(0) as usize, self.mask_len()) })
+    }
+
+    // Mirrors `make_network_boxes`, which allocates `coords - 4` mask values using
+    // the first detection layer, or the last layer if there is none.
+    pub(crate) fn mask_len(&self) -> usize {
+        let layers = self.layers();
+        let layer = layers
+            .iter()
+            .find(|l| {
+                matches!(
+                    l.type_,
+                    LAYER_TYPE::YOLO
+                        | LAYER_TYPE::GAUSSIAN_YOLO
+                        | LAYER_TYPE::DETECTION
+                        | LAYER_TYPE::REGION
+                )
+            })
+            .or_else(|| layers.last());
+        layer.map_or(0, |l| (l.coords - 4).max(0) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn treats_null_detections_as_empty() {
+        let dets = unsafe { Detections::from_raw(ptr::null_mut(), 3, 0) };
+        assert!(dets.is_empty());
+        assert_eq!(dets.len(), 0);
+        assert!(dets.get(0).is_none());
+        assert_eq!(dets.iter().len(), 0);
+        assert!(dets.raw().is_empty());
+    }
+
+    // Needs libdarknet built from source, see `crate::fixtures`.
+    #[cfg(all(
+        unix,
+        not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs"))
+    ))]
+    mod against_c {
+        use super::*;
+        use crate::fixtures::{self, make_detections, YOLO_CFG};
+        use std::path::Path;
+
+        fn detections(probs: &[&[f32]], mask_len: usize) -> Detections {
+            unsafe { Detections::from_raw(make_detections(probs, mask_len), probs.len(), mask_len) }
+        }
+
+        #[test]
+        fn handles_empty_arrays() {
+            let dets = detections(&[], 0);
+            assert!(dets.is_empty());
+            assert!(dets.iter().next().is_none());
+            let dets = detections(&[&[]], 0);
+            let det = dets.get(0).unwrap();
+            assert!(det.probabilities().is_empty());
+            assert_eq!(det.best_class(), None);
+            assert_eq!(det.mask(), None);
+            assert_eq!(det.uncertainty(), None);
+            assert_eq!(det.embedding(), None);
+        }
+
+        #[test]
+        fn iterates_in_both_directions() {
+            let dets = detections(&[&[0.1, 0.7], &[0.6, 0.2], &[0.3, 0.3]], 0);
+            assert_eq!(dets.len(), 3);
+            let iter = dets.iter();
+            assert_eq!(iter.len(), 3);
+            let x: Vec<_> = iter.map(|det| det.bbox().x).collect();
+            assert_eq!(x, [0.0, 0.1, 0.2]);
+            let x: Vec<_> = dets.iter().rev().map(|det| det.bbox().x).collect();
+            assert_eq!(x, [0.2, 0.1, 0.0]);
+            assert_eq!((&dets).into_iter().count(), 3);
+            assert!(dets.get(3).is_none());
+            let det = dets.get(1).unwrap();
+            assert_eq!(det.probabilities(), [0.6, 0.2]);
+            assert_eq!(det.objectness(), 0.9);
+            assert_eq!(det.raw().classes, 2);
+        }
+
+        #[test]
+        fn picks_the_best_class() {
+            let dets = detections(
+                &[
+                    &[0.1, 0.7, 0.2],
+                    &[0.3, 0.3],
+                    &[0.2, f32::NAN, 0.1],
+                    &[f32::NAN, 0.4],
+                    &[f32::NAN],
+                ],
+                0,
+            );
+            let best: Vec<_> = dets.iter().map(|det| det.best_class()).collect();
+            assert_eq!(
+                best[..4],
+                [
+                    Some((1, 0.7)),
+                    Some((0, 0.3)),
+                    Some((0, 0.2)),
+                    Some((1, 0.4))
+                ]
+            );
+            let (index, prob) = best[4].unwrap();
+            assert_eq!(index, 0);
+            assert!(prob.is_nan());
+        }
+
+        #[test]
+        fn exposes_masks_of_the_given_length() {
+            let dets = detections(&[&[0.5], &[0.5]], 2);
+            assert!(dets.iter().all(|det| det.mask() == Some(&[0.0, 0.0][..])));
+        }
+
+        #[test]
+        fn takes_the_mask_length_from_the_detection_layer() {
+            let yolo = fixtures::cfg_file("detection-yolo", YOLO_CFG);
+            let net = Network::load(&yolo, None::<&Path>, false).unwrap();
+            assert_eq!(net.mask_len(), 0);
+            // A region layer with 2 coordinates beyond the box, 2 anchors and 2
+            // classes.
+            let region = YOLO_CFG.replace("filters=21", "filters=18").replace(
+                "[yolo]\nmask=0,1,2\nanchors=4,4, 8,8, 16,16\nclasses=2\nnum=3",
+                "[region]\nanchors=1,1, 2,2\ncoords=6\nclasses=2\nnum=2",
+            );
+            assert!(region.contains("[region]"));
+            let region = fixtures::cfg_file("detection-region", &region);
+            let net = Network::load(&region, None::<&Path>, false).unwrap();
+            assert_eq!(net.mask_len(), 2);
+        }
+    }
+}
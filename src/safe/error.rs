@@ -0,0 +1,47 @@
+use crate::path::PathError;
+use std::{error, fmt, result};
+
+pub type Result<T, E = Error> = result::Result<T, E>;
+
+/// Errors returned by the safe wrappers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A path is invalid or names a missing file.
+    Path(PathError),
+    /// An argument is out of the range darknet accepts.
+    InvalidArgument(String),
+    /// darknet returned a null pointer.
+    NullPointer(&'static str),
+    /// A buffer does not have the length darknet expects.
+    LengthMismatch { expected: usize, actual: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Path(err) => err.fmt(f),
+            Error::InvalidArgument(msg) => f.write_str(msg),
+            Error::NullPointer(function) => write!(f, "{} returned a null pointer", function),
+            Error::LengthMismatch { expected, actual } => write!(
+                f,
+                "expected a buffer of {} elements, got {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Path(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<PathError> for Error {
+    fn from(err: PathError) -> Self {
+        Error::Path(err)
+    }
+}
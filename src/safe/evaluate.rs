@@ -0,0 +1,277 @@
+use super::{Detections, Error, Image, Network, Result};
+use crate::{
+    boxes::BBox,
+    diounms_sort, do_nms_sort, load_image_color,
+    metrics::{Evaluation, Evaluator, Truth},
+    nms::Detection,
+    path::{existing_path_to_cstring, PathError},
+    NMS_KIND,
+};
+use std::{fs, os::raw::c_int, path::Path};
+
+// Settings `validate_detector_map` hard-codes.
+const DETECTION_THRESH: f32 = 0.005;
+const NMS_THRESH: f32 = 0.45;
+
+/// Options of [`Network::evaluate_map`], with the defaults of `darknet detector map`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapOptions {
+    /// Confidence threshold of the counts, precision, recall and average IoU.
+    pub thresh: f32,
+    /// IoU a detection needs with a ground-truth box to count as a true positive.
+    pub iou_thresh: f32,
+    /// Number of recall points AP is interpolated at: 0 for the area under the
+    /// curve at every unique recall, 11 for VOC2007 and 101 for COCO.
+    pub map_points: usize,
+}
+
+impl Default for MapOptions {
+    fn default() -> Self {
+        Self {
+            thresh: 0.25,
+            iou_thresh: 0.5,
+            map_points: 0,
+        }
+    }
+}
+
+/// Results of [`Network::evaluate_map`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapReport {
+    /// Class names from the `names` file, indexed like `evaluation.classes`.
+    pub names: Vec<String>,
+    /// AP in full precision and the precision/recall curve of each class, and the
+    /// counts at [`MapOptions::thresh`].
+    pub evaluation: Evaluation,
+}
+
+impl Network {
+    /// Computes mAP on the validation set of a `.data` file like
+    /// `validate_detector_map`, reusing this network.
+    ///
+    /// The network must be loaded with batch size 1. It is evaluated on the `valid`
+    /// list of the data file, or `train` if there is none, with ground truth from
+    /// the label file next to each image. Images are loaded, resized and detected
+    /// on as darknet does, letterboxed if the cfg sets `letter_box`, and the
+    /// detections after NMS are scored by [`Evaluator`]. The `difficult` list of the
+    /// data file is not supported.
+    pub fn evaluate_map<P: AsRef<Path>>(
+        &mut self,
+        data: P,
+        options: &MapOptions,
+    ) -> Result<MapReport> {
+        if self.batch() != 1 {
+            return Err(Error::InvalidArgument(format!(
+                "evaluation needs a network with batch size 1, got {}",
+                self.batch()
+            )));
+        }
+        if self.channels() != 3 {
+            return Err(Error::InvalidArgument(format!(
+                "evaluation needs a network with 3 input channels, got {}",
+                self.channels()
+            )));
+        }
+        // darknet runs NMS and counts classes with the last layer's settings.
+        let (classes, nms_kind, beta_nms) = match self.layers().last() {
+            Some(last) => (last.classes, last.nms_kind, last.beta_nms),
+            None => {
+                return Err(Error::InvalidArgument(
+                    "the network has no layers".to_owned(),
+                ))
+            }
+        };
+        let num_classes = classes.max(0) as usize;
+        let (valid, names) = read_data_file(data.as_ref())?;
+        let names = read_lines(Path::new(&names))?;
+        if names.len() != num_classes {
+            return Err(Error::InvalidArgument(format!(
+                "the names file has {} names, but the last layer has {} classes",
+                names.len(),
+                num_classes
+            )));
+        }
+
+        let letterbox = self.raw().letter_box != 0;
+        let mut evaluator = Evaluator::new(num_classes);
+        for image in read_lines(Path::new(&valid))? {
+            let mut dets = self.detect_image(&image, letterbox)?;
+            let num = dets.len() as c_int;
+            unsafe {
+                if nms_kind == NMS_KIND::DEFAULT_NMS {
+                    do_nms_sort(dets.as_mut_ptr(), num, classes, NMS_THRESH);
+                } else {
+                    diounms_sort(
+                        dets.as_mut_ptr(),
+                        num,
+                        classes,
+                        NMS_THRESH,
+                        nms_kind,
+                        beta_nms,
+                    );
+                }
+            }
+            let dets: Vec<Detection> = dets.iter().map(Detection::from).collect();
+            let truths = read_labels(&label_path(&image), num_classes)?;
+            evaluator.add_image(&dets, &truths);
+        }
+        Ok(MapReport {
+            names,
+            evaluation: evaluator.evaluate(options.thresh, options.iou_thresh, options.map_points),
+        })
+    }
+
+    // Runs the network on an image file, with boxes relative to the image size.
+    fn detect_image(&mut self, path: &str, letterbox: bool) -> Result<Detections> {
+        let path = existing_path_to_cstring(Path::new(path))?;
+        // darknet does not modify the path, and loads a blank image from files it
+        // cannot decode.
+        let image = unsafe { Image::from_raw(load_image_color(path.as_ptr() as *mut _, 0, 0)) };
+        let (width, height) = (self.width(), self.height());
+        let sized = if letterbox {
+            image.letterbox(width, height)?
+        } else {
+            image.resize(width, height)?
+        };
+        self.predict(sized.data())?;
+        if letterbox {
+            self.detections(
+                image.width(),
+                image.height(),
+                DETECTION_THRESH,
+                0.0,
+                true,
+                true,
+            )
+        } else {
+            self.detections(1, 1, DETECTION_THRESH, 0.0, false, false)
+        }
+    }
+}
+
+// Returns the image list and names file of a `.data` file, which are `key=value`
+// lines where darknet ignores all whitespace.
+fn read_data_file(path: &Path) -> Result<(String, String)> {
+    let contents = fs::read_to_string(path).map_err(|_| PathError::NotFound(path.to_owned()))?;
+    let options: Vec<(String, String)> = contents
+        .lines()
+        .map(|line| line.split_whitespace().collect::<String>())
+        .filter(|line| !line.starts_with('#') && !line.starts_with(';'))
+        .filter_map(|line| {
+            line.split_once('=')
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        })
+        .collect();
+    let option = |key: &str| {
+        options
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.clone())
+    };
+    let valid = option("valid")
+        .or_else(|| option("train"))
+        .unwrap_or_else(|| "data/train.txt".to_owned());
+    let names = option("names").unwrap_or_else(|| "data/names.list".to_owned());
+    Ok((valid, names))
+}
+
+// The non-empty lines of a list file.
+fn read_lines(path: &Path) -> Result<Vec<String>> {
+    let contents = fs::read_to_string(path).map_err(|_| PathError::NotFound(path.to_owned()))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect())
+}
+
+// The label file of an image as darknet's `replace_image_to_label` finds it: next
+// to the image, or in `labels` for COCO and Pascal VOC directory layouts.
+fn label_path(image: &str) -> String {
+    let mut path = image.to_owned();
+    for (from, to) in &[
+        ("/images/train2017/", "/labels/train2017/"),
+        ("/images/val2017/", "/labels/val2017/"),
+        ("/JPEGImages/", "/labels/"),
+        ("\\images\\train2017\\", "\\labels\\train2017\\"),
+        ("\\images\\val2017\\", "\\labels\\val2017\\"),
+        ("\\JPEGImages\\", "\\labels\\"),
+    ] {
+        path = path.replacen(from, to, 1);
+    }
+    let path = path.trim();
+    for ext in &[
+        ".jpg", ".JPG", ".jpeg", ".JPEG", ".png", ".PNG", ".bmp", ".BMP", ".ppm", ".PPM", ".tiff",
+        ".TIFF", ".tif", ".TIF",
+    ] {
+        if let Some(stem) = path.strip_suffix(ext) {
+            return format!("{}.txt", stem);
+        }
+    }
+    path.to_owned()
+}
+
+// Reads `class x y w h` lines up to the first malformed one, like darknet's
+// `read_boxes`. An image without a label file has no ground truth.
+fn read_labels(path: &str, classes: usize) -> Result<Vec<Truth>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let mut truths = Vec::new();
+    let mut fields = contents.split_whitespace();
+    while let Some(truth) = parse_label(&mut fields) {
+        if truth.class_id >= classes {
+            return Err(Error::InvalidArgument(format!(
+                "{} has a box of class {}, but there are {} classes",
+                path, truth.class_id, classes
+            )));
+        }
+        truths.push(truth);
+    }
+    Ok(truths)
+}
+
+fn parse_label<'a, I>(fields: &mut I) -> Option<Truth>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let class_id = fields.next()?.parse().ok()?;
+    let mut value = || fields.next()?.parse::<f32>().ok();
+    let bbox = BBox::new(value()?, value()?, value()?, value()?);
+    Some(Truth::new(class_id, bbox))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_label_files_like_darknet() {
+        assert_eq!(label_path("data/obj/1.jpg"), "data/obj/1.txt");
+        assert_eq!(
+            label_path("coco/images/val2017/2.JPEG"),
+            "coco/labels/val2017/2.txt"
+        );
+        assert_eq!(
+            label_path("VOC2007/JPEGImages/3.png "),
+            "VOC2007/labels/3.txt"
+        );
+        assert_eq!(label_path("data/obj/images/4.bmp"), "data/obj/images/4.txt");
+    }
+
+    #[test]
+    fn reads_labels_up_to_the_first_malformed_line() {
+        let mut fields =
+            "0 0.5 0.5 0.2 0.2\n1 0.25 0.75 0.1 0.3\nbad 0 0 0 0\n2 0 0 0 0".split_whitespace();
+        let truths: Vec<_> = std::iter::from_fn(|| parse_label(&mut fields)).collect();
+        assert_eq!(
+            truths,
+            [
+                Truth::new(0, BBox::new(0.5, 0.5, 0.2, 0.2)),
+                Truth::new(1, BBox::new(0.25, 0.75, 0.1, 0.3)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use super::{Dims, Error, Network, Result};
+
+/// A copy of one layer's output for one batch item, planar (channel, row, column).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureMap {
+    dims: Dims,
+    data: Vec<f32>,
+}
+
+impl FeatureMap {
+    pub fn dims(&self) -> Dims {
+        self.dims
+    }
+
+    /// Dimensions as `(channels, height, width)`, the order of [`FeatureMap::data`].
+    pub fn shape(&self) -> (usize, usize, usize) {
+        (self.dims.channels, self.dims.height, self.dims.width)
+    }
+
+    /// The values, `channels * height * width` of them.
+    pub fn data(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// Returns the value at `channel`, row `y` and column `x`.
+    pub fn get(&self, channel: usize, y: usize, x: usize) -> Option<f32> {
+        let Dims {
+            width,
+            height,
+            channels,
+        } = self.dims;
+        if channel >= channels || y >= height || x >= width {
+            return None;
+        }
+        Some(self.data[(channel * height + y) * width + x])
+    }
+
+    pub fn into_vec(self) -> Vec<f32> {
+        self.data
+    }
+}
+
+impl Network {
+    /// Copies the output of the layer at `index` for batch item `batch_item` from
+    /// the last forward pass, e.g. of [`Network::predict`].
+    ///
+    /// darknet's layers have no names. `index` counts the sections of the cfg after
+    /// `[net]` from 0, like [`Network::layer`] and the indices darknet prints while
+    /// loading a network.
+    ///
+    /// With the `enable-cuda` feature and a GPU selected through `gpu_index`, the
+    /// output is first copied to the host with `cuda_pull_array`, since darknet only
+    /// does that for the last layer.
+    pub fn feature_map(&mut self, index: usize, batch_item: usize) -> Result<FeatureMap> {
+        let layer = self.layer(index).ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "layer {} is out of range for a network of {} layers",
+                index,
+                self.layers().len()
+            ))
+        })?;
+        let (dims, outputs, batch) = (layer.output_dims(), layer.outputs(), layer.batch());
+        if batch_item >= batch {
+            return Err(Error::InvalidArgument(format!(
+                "batch item {} is out of range for a batch of {}",
+                batch_item, batch
+            )));
+        }
+        if dims.len() != outputs {
+            return Err(Error::InvalidArgument(format!(
+                "the {} outputs of layer {} do not form a {}x{}x{} map",
+                outputs, index, dims.channels, dims.height, dims.width
+            )));
+        }
+        #[cfg(feature = "enable-cuda")]
+        self.pull_output(index);
+
+        let output = self.layers()[index].output;
+        if output.is_null() {
+            return Err(Error::InvalidArgument(format!(
+                "layer {} has no output buffer",
+                index
+            )));
+        }
+        let output = unsafe { std::slice::from_raw_parts(output, batch * outputs) };
+        Ok(FeatureMap {
+            dims,
+            data: output[batch_item * outputs..][..outputs].to_vec(),
+        })
+    }
+
+    #[cfg(feature = "enable-cuda")]
+    fn pull_output(&mut self, index: usize) {
+        let layer = &self.layers()[index];
+        let len = layer.batch.max(0) as usize * layer.outputs.max(0) as usize;
+        if unsafe { crate::gpu_index } >= 0
+            && !layer.output_gpu.is_null()
+            && !layer.output.is_null()
+        {
+            unsafe { crate::cuda_pull_array(layer.output_gpu, layer.output, len) };
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod conversions {
+    use super::FeatureMap;
+    use ndarray::{Array3, ArrayView3};
+
+    impl FeatureMap {
+        /// Borrows the values as an array of shape [`FeatureMap::shape`].
+        pub fn view(&self) -> ArrayView3<'_, f32> {
+            ArrayView3::from_shape(self.shape(), &self.data).expect("data matches dims")
+        }
+    }
+
+    impl From<FeatureMap> for Array3<f32> {
+        fn from(map: FeatureMap) -> Self {
+            Array3::from_shape_vec(map.shape(), map.data).expect("data matches dims")
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::safe::Dims;
+
+        #[test]
+        fn array_matches_planar_layout() {
+            let map = FeatureMap {
+                dims: Dims {
+                    width: 3,
+                    height: 2,
+                    channels: 2,
+                },
+                data: (0..12).map(|value| value as f32).collect(),
+            };
+            assert_eq!(map.get(1, 0, 2), Some(8.0));
+            assert_eq!(map.view()[[1, 0, 2]], 8.0);
+            let array = Array3::from(map);
+            assert_eq!(array.dim(), (2, 2, 3));
+            assert_eq!(array[[0, 1, 0]], 3.0);
+        }
+    }
+}
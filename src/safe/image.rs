@@ -0,0 +1,345 @@
+use super::{to_c_int, Error, Result};
+use crate::{
+    copy_image_from_bytes, crop_image, free_image, image, letterbox_image, make_image,
+    resize_image, resize_min,
+};
+use std::{convert::TryFrom, mem::ManuallyDrop, os::raw::c_int, slice};
+
+/// An image owned by darknet's allocator, freed with `free_image` on drop.
+///
+/// Pixels are stored as `f32` in `[0, 1]`, planar (channel, row, column), which is
+/// what darknet's networks consume. Color images are expected in RGB order.
+#[derive(Debug)]
+pub struct Image {
+    image: image,
+}
+
+impl Image {
+    /// Creates a zero-filled image with `make_image`.
+    ///
+    /// Fails if darknet cannot count the values, i.e. `width * height * channels`
+    /// does not fit in a C `int`.
+    pub fn new(width: usize, height: usize, channels: usize) -> Result<Self> {
+        let len = checked_len(width, height, channels)?;
+        let (w, h, c) = (to_c_int(width)?, to_c_int(height)?, to_c_int(channels)?);
+        let image = unsafe { make_image(w, h, c) };
+        if image.data.is_null() && len != 0 {
+            return Err(Error::NullPointer("make_image"));
+        }
+        Ok(Self { image })
+    }
+
+    /// Creates an image from interleaved 8-bit pixels (row, column, channel), e.g.
+    /// an RGB buffer, using `copy_image_from_bytes`.
+    pub fn from_interleaved_u8(
+        width: usize,
+        height: usize,
+        channels: usize,
+        data: &[u8],
+    ) -> Result<Self> {
+        let expected = checked_len(width, height, channels)?;
+        if data.len() != expected {
+            return Err(Error::LengthMismatch {
+                expected,
+                actual: data.len(),
+            });
+        }
+        let image = Self::new(width, height, channels)?;
+        if expected != 0 {
+            // darknet only reads from the source buffer.
+            unsafe { copy_image_from_bytes(image.image, data.as_ptr() as *mut _) };
+        }
+        Ok(image)
+    }
+
+    /// Converts to interleaved 8-bit pixels (row, column, channel), clamping values
+    /// to `[0, 1]` before scaling.
+    pub fn to_interleaved_u8(&self) -> Vec<u8> {
+        let (w, h, c) = (self.width(), self.height(), self.channels());
+        let data = self.data();
+        let mut out = vec![0; w * h * c];
+        for k in 0..c {
+            for y in 0..h {
+                for x in 0..w {
+                    let value = data[k * w * h + y * w + x];
+                    out[(y * w + x) * c + k] = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+                }
+            }
+        }
+        out
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> usize {
+        self.image.w as usize
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> usize {
+        self.image.h as usize
+    }
+
+    /// Number of channels.
+    pub fn channels(&self) -> usize {
+        self.image.c as usize
+    }
+
+    /// Planar pixel data, `channels * height * width` values.
+    pub fn data(&self) -> &[f32] {
+        if self.image.data.is_null() {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(self.image.data, self.len()) }
+    }
+
+    /// Mutable planar pixel data.
+    pub fn data_mut(&mut self) -> &mut [f32] {
+        if self.image.data.is_null() {
+            return &mut [];
+        }
+        unsafe { slice::from_raw_parts_mut(self.image.data, self.len()) }
+    }
+
+    /// Resizes to `width` x `height` keeping the aspect ratio, padding the rest with
+    /// gray, using `letterbox_image`.
+    pub fn letterbox(&self, width: usize, height: usize) -> Result<Self> {
+        let (w, h) = (to_c_int(width)?, to_c_int(height)?);
+        Ok(unsafe { Self::from_raw(letterbox_image(self.image, w, h)) })
+    }
+
+    /// Resizes to `width` x `height` with `resize_image`.
+    pub fn resize(&self, width: usize, height: usize) -> Result<Self> {
+        let (w, h) = (to_c_int(width)?, to_c_int(height)?);
+        Ok(unsafe { Self::from_raw(resize_image(self.image, w, h)) })
+    }
+
+    /// Resizes so that the shorter side is `min` pixels with `resize_min`.
+    pub fn resize_min(&self, min: usize) -> Result<Self> {
+        let min = to_c_int(min)?;
+        Ok(unsafe { Self::from_raw(resize_min(self.image, min)) })
+    }
+
+    /// Crops a `width` x `height` region starting at (`x`, `y`) with `crop_image`.
+    ///
+    /// Pixels outside of the source image are clamped to its border.
+    pub fn crop(&self, x: isize, y: isize, width: usize, height: usize) -> Result<Self> {
+        let (dx, dy) = (to_c_int_signed(x)?, to_c_int_signed(y)?);
+        let (w, h) = (to_c_int(width)?, to_c_int(height)?);
+        Ok(unsafe { Self::from_raw(crop_image(self.image, dx, dy, w, h)) })
+    }
+
+    /// Returns a copy of the raw `image` struct. The data stays owned by `self`.
+    pub fn as_raw(&self) -> image {
+        self.image
+    }
+
+    /// Releases ownership of the raw `image`. It must be freed with `free_image`.
+    pub fn into_raw(self) -> image {
+        ManuallyDrop::new(self).image
+    }
+
+    /// Takes ownership of an `image` allocated by darknet.
+    ///
+    /// # Safety
+    ///
+    /// `image.data` must be null or point to `w * h * c` floats allocated by darknet
+    /// that nothing else frees.
+    pub unsafe fn from_raw(image: image) -> Self {
+        Self { image }
+    }
+
+    fn len(&self) -> usize {
+        self.width() * self.height() * self.channels()
+    }
+}
+
+impl Clone for Image {
+    fn clone(&self) -> Self {
+        let mut image = Self::new(self.width(), self.height(), self.channels())
+            .expect("make_image failed for existing dimensions");
+        image.data_mut().copy_from_slice(self.data());
+        image
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        if !self.image.data.is_null() {
+            unsafe { free_image(self.image) };
+        }
+    }
+}
+
+// Number of values of an image, which darknet computes as `int`.
+fn checked_len(width: usize, height: usize, channels: usize) -> Result<usize> {
+    let (w, h, c) = (to_c_int(width)?, to_c_int(height)?, to_c_int(channels)?);
+    w.checked_mul(h)
+        .and_then(|len| len.checked_mul(c))
+        .map(|len| len as usize)
+        .ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "a {}x{}x{} image is too large for darknet",
+                width, height, channels
+            ))
+        })
+}
+
+fn to_c_int_signed(value: isize) -> Result<c_int> {
+    c_int::try_from(value)
+        .map_err(|_| Error::InvalidArgument(format!("image offset {} is out of range", value)))
+}
+
+#[cfg(feature = "image")]
+mod conversions {
+    use super::Image;
+    use crate::safe::{Error, Result};
+    use ::image::{DynamicImage, GrayImage, RgbImage, RgbaImage};
+    use std::convert::TryFrom;
+
+    impl From<&RgbImage> for Image {
+        fn from(from: &RgbImage) -> Self {
+            Image::from_interleaved_u8(from.width() as usize, from.height() as usize, 3, from)
+                .expect("RgbImage buffer matches its dimensions")
+        }
+    }
+
+    impl From<&DynamicImage> for Image {
+        /// Converts to RGB, dropping any alpha channel.
+        fn from(from: &DynamicImage) -> Self {
+            match from {
+                DynamicImage::ImageRgb8(rgb) => rgb.into(),
+                other => (&other.to_rgb8()).into(),
+            }
+        }
+    }
+
+    impl TryFrom<&Image> for RgbImage {
+        type Error = Error;
+
+        fn try_from(from: &Image) -> Result<Self> {
+            if from.channels() != 3 {
+                return Err(Error::InvalidArgument(format!(
+                    "expected 3 channels, got {}",
+                    from.channels()
+                )));
+            }
+            Ok(RgbImage::from_raw(
+                from.width() as u32,
+                from.height() as u32,
+                from.to_interleaved_u8(),
+            )
+            .expect("buffer matches image dimensions"))
+        }
+    }
+
+    impl TryFrom<&Image> for DynamicImage {
+        type Error = Error;
+
+        /// Converts 1, 3 and 4 channel images to gray, RGB and RGBA respectively.
+        fn try_from(from: &Image) -> Result<Self> {
+            let (w, h) = (from.width() as u32, from.height() as u32);
+            let data = from.to_interleaved_u8();
+            let image = match from.channels() {
+                1 => GrayImage::from_raw(w, h, data).map(DynamicImage::ImageLuma8),
+                3 => RgbImage::from_raw(w, h, data).map(DynamicImage::ImageRgb8),
+                4 => RgbaImage::from_raw(w, h, data).map(DynamicImage::ImageRgba8),
+                channels => {
+                    return Err(Error::InvalidArgument(format!(
+                        "cannot convert an image with {} channels",
+                        channels
+                    )))
+                }
+            };
+            Ok(image.expect("buffer matches image dimensions"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_sizes_darknet_cannot_count() {
+        for (w, h, c) in [(1 << 16, 1 << 16, 3), (usize::MAX, 1, 1)] {
+            assert!(matches!(
+                Image::new(w, h, c),
+                Err(Error::InvalidArgument(_))
+            ));
+            assert!(matches!(
+                Image::from_interleaved_u8(w, h, c, &[]),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn checks_the_buffer_length() {
+        assert_eq!(
+            Image::from_interleaved_u8(2, 2, 3, &[0; 11]).unwrap_err(),
+            Error::LengthMismatch {
+                expected: 12,
+                actual: 11
+            }
+        );
+    }
+
+    // Needs libdarknet built from source, see `crate::fixtures`.
+    #[cfg(not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs")))]
+    mod against_c {
+        use super::*;
+
+        // A 2x2 RGB image with a different value in every channel of every pixel.
+        const PIXELS: [u8; 12] = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100, 110, 120];
+
+        #[test]
+        fn stores_interleaved_pixels_planar() {
+            let image = Image::from_interleaved_u8(2, 2, 3, &PIXELS).unwrap();
+            assert_eq!((image.width(), image.height(), image.channels()), (2, 2, 3));
+            let planar: Vec<_> = image
+                .data()
+                .iter()
+                .map(|value| (value * 255.0).round() as u8)
+                .collect();
+            assert_eq!(planar, [10, 40, 70, 100, 20, 50, 80, 110, 30, 60, 90, 120]);
+            assert_eq!(image.to_interleaved_u8(), PIXELS);
+            assert_eq!(image.clone().to_interleaved_u8(), PIXELS);
+        }
+
+        #[cfg(feature = "image")]
+        #[test]
+        fn converts_image_crate_images() {
+            use ::image::{DynamicImage, GrayImage, RgbImage, RgbaImage};
+
+            let rgb = RgbImage::from_raw(2, 2, PIXELS.to_vec()).unwrap();
+            let image = Image::from(&rgb);
+            assert_eq!(image.to_interleaved_u8(), PIXELS);
+            assert_eq!(RgbImage::try_from(&image).unwrap(), rgb);
+            assert_eq!(
+                DynamicImage::try_from(&image).unwrap(),
+                DynamicImage::ImageRgb8(rgb.clone())
+            );
+
+            // Alpha is dropped.
+            let rgba = RgbaImage::from_fn(2, 2, |x, y| {
+                let [r, g, b] = rgb.get_pixel(x, y).0;
+                ::image::Rgba([r, g, b, 255 - r])
+            });
+            let image = Image::from(&DynamicImage::ImageRgba8(rgba));
+            assert_eq!(image.channels(), 3);
+            assert_eq!(image.to_interleaved_u8(), PIXELS);
+
+            let gray = GrayImage::from_raw(2, 2, vec![0, 85, 170, 255]).unwrap();
+            let mut image = Image::new(2, 2, 1).unwrap();
+            image
+                .data_mut()
+                .copy_from_slice(&[0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+            assert_eq!(
+                DynamicImage::try_from(&image).unwrap(),
+                DynamicImage::ImageLuma8(gray)
+            );
+            assert!(RgbImage::try_from(&image).is_err());
+            assert!(DynamicImage::try_from(&Image::new(2, 2, 2).unwrap()).is_err());
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use super::Network;
+use crate::{layer, ACTIVATION, LAYER_TYPE};
+use std::{os::raw::c_int, slice};
+
+/// Width, height and channels of a layer's input or output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dims {
+    pub width: usize,
+    pub height: usize,
+    pub channels: usize,
+}
+
+impl Dims {
+    /// Number of values, `width * height * channels`.
+    pub fn len(&self) -> usize {
+        self.width * self.height * self.channels
+    }
+
+    /// Returns `true` if any dimension is 0.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A read-only view of one layer of a [`Network`].
+///
+/// Most fields of darknet's `layer` struct only apply to some layer types. The
+/// accessors here return `None` for layer types a value does not apply to, and
+/// [`LayerView::raw`] gives access to everything else.
+#[derive(Debug, Clone, Copy)]
+pub struct LayerView<'a> {
+    raw: &'a layer,
+    index: usize,
+}
+
+impl<'a> LayerView<'a> {
+    /// Position of the layer in the network.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn layer_type(&self) -> LAYER_TYPE {
+        self.raw.type_
+    }
+
+    /// Activation function. Only meaningful for layers that apply one, such as
+    /// convolutional and connected layers.
+    pub fn activation(&self) -> ACTIVATION {
+        self.raw.activation
+    }
+
+    pub fn input_dims(&self) -> Dims {
+        Dims {
+            width: to_usize(self.raw.w),
+            height: to_usize(self.raw.h),
+            channels: to_usize(self.raw.c),
+        }
+    }
+
+    pub fn output_dims(&self) -> Dims {
+        Dims {
+            width: to_usize(self.raw.out_w),
+            height: to_usize(self.raw.out_h),
+            channels: to_usize(self.raw.out_c),
+        }
+    }
+
+    /// Batch size the layer was allocated for.
+    pub fn batch(&self) -> usize {
+        to_usize(self.raw.batch)
+    }
+
+    /// Number of input values per batch item.
+    pub fn inputs(&self) -> usize {
+        to_usize(self.raw.inputs)
+    }
+
+    /// Number of output values per batch item.
+    pub fn outputs(&self) -> usize {
+        to_usize(self.raw.outputs)
+    }
+
+    /// Number of filters of convolutional, deconvolutional and local layers.
+    pub fn filters(&self) -> Option<usize> {
+        match self.raw.type_ {
+            LAYER_TYPE::CONVOLUTIONAL | LAYER_TYPE::DECONVOLUTIONAL | LAYER_TYPE::LOCAL => {
+                Some(to_usize(self.raw.n))
+            }
+            _ => None,
+        }
+    }
+
+    /// Kernel size of layers with a sliding window, including pooling layers.
+    pub fn size(&self) -> Option<usize> {
+        self.has_window().then(|| to_usize(self.raw.size))
+    }
+
+    /// Horizontal and vertical stride of layers with a sliding window.
+    pub fn stride(&self) -> Option<(usize, usize)> {
+        self.has_window().then(|| {
+            let (x, y) = (self.raw.stride_x, self.raw.stride_y);
+            // Deconvolutional layers only set `stride`.
+            if x > 0 && y > 0 {
+                (to_usize(x), to_usize(y))
+            } else {
+                (to_usize(self.raw.stride), to_usize(self.raw.stride))
+            }
+        })
+    }
+
+    /// Indices of the layers a route or shortcut layer reads from.
+    pub fn input_layers(&self) -> Option<&'a [i32]> {
+        match self.raw.type_ {
+            LAYER_TYPE::ROUTE | LAYER_TYPE::SHORTCUT => {
+                Some(unsafe { slice_or_empty(self.raw.input_layers, self.raw.n) })
+            }
+            _ => None,
+        }
+    }
+
+    /// Output of the last forward pass, [`LayerView::batch`] times
+    /// [`LayerView::outputs`] values in planar layout.
+    ///
+    /// With CUDA the output is only copied to the host when a later layer or the
+    /// caller asks for it, so this may be stale for GPU networks.
+    pub fn output(&self) -> &'a [f32] {
+        unsafe { slice_or_empty(self.raw.output, self.raw.batch * self.raw.outputs) }
+    }
+
+    /// Parameters of `[yolo]` and `[Gaussian_yolo]` layers.
+    pub fn yolo(&self) -> Option<Yolo<'a>> {
+        match self.raw.type_ {
+            LAYER_TYPE::YOLO | LAYER_TYPE::GAUSSIAN_YOLO => Some(Yolo { raw: self.raw }),
+            _ => None,
+        }
+    }
+
+    /// Parameters of `[region]` layers.
+    pub fn region(&self) -> Option<Region<'a>> {
+        match self.raw.type_ {
+            LAYER_TYPE::REGION => Some(Region { raw: self.raw }),
+            _ => None,
+        }
+    }
+
+    /// The underlying `layer` struct.
+    pub fn raw(&self) -> &'a layer {
+        self.raw
+    }
+
+    fn has_window(&self) -> bool {
+        matches!(
+            self.raw.type_,
+            LAYER_TYPE::CONVOLUTIONAL
+                | LAYER_TYPE::DECONVOLUTIONAL
+                | LAYER_TYPE::LOCAL
+                | LAYER_TYPE::MAXPOOL
+                | LAYER_TYPE::LOCAL_AVGPOOL
+        )
+    }
+}
+
+/// Parameters of a `[yolo]` or `[Gaussian_yolo]` layer.
+#[derive(Debug, Clone, Copy)]
+pub struct Yolo<'a> {
+    raw: &'a layer,
+}
+
+impl<'a> Yolo<'a> {
+    pub fn classes(&self) -> usize {
+        to_usize(self.raw.classes)
+    }
+
+    /// All anchors of the cfg as width and height in pixels of the network input,
+    /// `num=` pairs.
+    pub fn anchors(&self) -> &'a [[f32; 2]] {
+        unsafe { pairs_or_empty(self.raw.biases, self.raw.total) }
+    }
+
+    /// Indices into [`Yolo::anchors`] of the anchors this layer predicts.
+    pub fn mask(&self) -> &'a [i32] {
+        unsafe { slice_or_empty(self.raw.mask, self.raw.n) }
+    }
+
+    pub fn scale_x_y(&self) -> f32 {
+        self.raw.scale_x_y
+    }
+
+    pub fn ignore_thresh(&self) -> f32 {
+        self.raw.ignore_thresh
+    }
+
+    pub fn truth_thresh(&self) -> f32 {
+        self.raw.truth_thresh
+    }
+}
+
+/// Parameters of a `[region]` layer.
+#[derive(Debug, Clone, Copy)]
+pub struct Region<'a> {
+    raw: &'a layer,
+}
+
+impl<'a> Region<'a> {
+    pub fn classes(&self) -> usize {
+        to_usize(self.raw.classes)
+    }
+
+    /// Number of box coordinates, 4 unless the layer also predicts masks.
+    pub fn coords(&self) -> usize {
+        to_usize(self.raw.coords)
+    }
+
+    /// Anchors as width and height in cells of the output grid, `num=` pairs.
+    pub fn anchors(&self) -> &'a [[f32; 2]] {
+        unsafe { pairs_or_empty(self.raw.biases, self.raw.n) }
+    }
+}
+
+impl Network {
+    /// Returns a view of the layer at `index`.
+    pub fn layer(&self, index: usize) -> Option<LayerView<'_>> {
+        self.layers().get(index).map(|raw| LayerView { raw, index })
+    }
+
+    /// Views of all layers, in order.
+    pub fn layer_views(&self) -> impl ExactSizeIterator<Item = LayerView<'_>> {
+        self.layers()
+            .iter()
+            .enumerate()
+            .map(|(index, raw)| LayerView { raw, index })
+    }
+}
+
+fn to_usize(value: c_int) -> usize {
+    value.max(0) as usize
+}
+
+unsafe fn slice_or_empty<'a, T>(data: *const T, len: c_int) -> &'a [T] {
+    if data.is_null() || len <= 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len as usize)
+    }
+}
+
+unsafe fn pairs_or_empty<'a>(data: *const f32, len: c_int) -> &'a [[f32; 2]] {
+    slice_or_empty(data as *const [f32; 2], len)
+}
+
+// Needs libdarknet built from source, see `crate::fixtures`.
+#[cfg(all(
+    test,
+    unix,
+    not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs"))
+))]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, YOLO_CFG};
+    use std::path::Path;
+
+    fn dims(width: usize, height: usize, channels: usize) -> Dims {
+        Dims {
+            width,
+            height,
+            channels,
+        }
+    }
+
+    #[test]
+    fn describes_the_layers_of_the_cfg() {
+        let cfg = fixtures::cfg_file("layer", YOLO_CFG);
+        let net = Network::load(&cfg, None::<&Path>, false).unwrap();
+        assert_eq!(net.layer_views().len(), 3);
+        assert!(net.layer(3).is_none());
+        let types: Vec<_> = net.layer_views().map(|l| l.layer_type()).collect();
+        assert_eq!(
+            types,
+            [
+                LAYER_TYPE::CONVOLUTIONAL,
+                LAYER_TYPE::CONVOLUTIONAL,
+                LAYER_TYPE::YOLO
+            ]
+        );
+
+        let conv = net.layer(0).unwrap();
+        assert_eq!(conv.index(), 0);
+        assert_eq!(conv.input_dims(), dims(32, 32, 3));
+        assert_eq!(conv.output_dims(), dims(16, 16, 8));
+        assert_eq!((conv.inputs(), conv.outputs()), (32 * 32 * 3, 16 * 16 * 8));
+        assert_eq!(conv.batch(), 1);
+        assert_eq!(conv.activation(), ACTIVATION::LEAKY);
+        assert_eq!(conv.filters(), Some(8));
+        assert_eq!(conv.size(), Some(3));
+        assert_eq!(conv.stride(), Some((2, 2)));
+        assert!(conv.yolo().is_none() && conv.region().is_none());
+        assert!(conv.input_layers().is_none());
+
+        let head = net.layer(1).unwrap();
+        assert_eq!(head.output_dims(), dims(16, 16, 21));
+        assert_eq!(head.activation(), ACTIVATION::LINEAR);
+        assert_eq!((head.size(), head.stride()), (Some(1), Some((1, 1))));
+
+        let yolo_layer = net.layer(2).unwrap();
+        assert_eq!(yolo_layer.input_dims(), dims(16, 16, 21));
+        assert_eq!(yolo_layer.output_dims(), dims(16, 16, 21));
+        assert_eq!((yolo_layer.filters(), yolo_layer.size()), (None, None));
+        let yolo = yolo_layer.yolo().unwrap();
+        assert_eq!(yolo.classes(), 2);
+        assert_eq!(yolo.mask(), [0, 1, 2]);
+        assert_eq!(yolo.anchors(), [[4.0, 4.0], [8.0, 8.0], [16.0, 16.0]]);
+        assert_eq!(yolo.scale_x_y(), 1.0);
+    }
+}
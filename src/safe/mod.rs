@@ -0,0 +1,30 @@
+//! Safe wrappers around libdarknet (`safe` feature).
+//!
+//! The types here own the darknet allocations they wrap and release them with the
+//! matching `free_*` function on drop. Raw pointers remain reachable through
+//! `as_ptr()`-style accessors for functionality not covered yet.
+
+mod batch;
+mod detection;
+mod error;
+mod evaluate;
+mod feature_map;
+mod image;
+mod layer;
+mod network;
+
+pub use self::image::Image;
+pub use detection::{Detection, Detections, Iter};
+pub use error::{Error, Result};
+pub use evaluate::{MapOptions, MapReport};
+pub use feature_map::FeatureMap;
+pub use layer::{Dims, LayerView, Region, Yolo};
+pub use network::Network;
+
+use std::{convert::TryFrom, os::raw::c_int};
+
+/// Converts a size or count to `c_int`, failing if it does not fit.
+pub(crate) fn to_c_int(value: usize) -> Result<c_int> {
+    c_int::try_from(value)
+        .map_err(|_| Error::InvalidArgument(format!("{} is too large for darknet", value)))
+}
@@ -0,0 +1,217 @@
+use super::{Error, Result};
+use crate::path::existing_path_to_cstring;
+use crate::{
+    free_network_ptr, layer, load_network_custom, network, network_height, network_predict_ptr,
+    network_width,
+};
+use std::{convert::TryFrom, os::raw::c_int, path::Path, ptr::NonNull, slice};
+
+/// A network loaded with `load_network_custom`, freed with `free_network_ptr` on drop.
+#[derive(Debug)]
+pub struct Network {
+    net: NonNull<network>,
+}
+
+impl Network {
+    /// Loads a network from a `.cfg` file and optional `.weights` file with a batch
+    /// size of 1.
+    ///
+    /// If `clear` is set, the `seen` counter stored in the weights is reset.
+    pub fn load<C, W>(cfg: C, weights: Option<W>, clear: bool) -> Result<Self>
+    where
+        C: AsRef<Path>,
+        W: AsRef<Path>,
+    {
+        Self::load_with_batch(cfg, weights, clear, 1)
+    }
+
+    /// Loads a network like [`Network::load`] with the given batch size.
+    pub fn load_with_batch<C, W>(
+        cfg: C,
+        weights: Option<W>,
+        clear: bool,
+        batch: usize,
+    ) -> Result<Self>
+    where
+        C: AsRef<Path>,
+        W: AsRef<Path>,
+    {
+        let cfg = existing_path_to_cstring(cfg.as_ref())?;
+        let weights = weights
+            .map(|weights| existing_path_to_cstring(weights.as_ref()))
+            .transpose()?;
+        let batch = c_int::try_from(batch)
+            .ok()
+            .filter(|&batch| batch > 0)
+            .ok_or_else(|| Error::InvalidArgument(format!("invalid batch size {}", batch)))?;
+        // darknet does not modify the path strings, and skips loading weights for
+        // a null pointer.
+        let ptr = unsafe {
+            load_network_custom(
+                cfg.as_ptr() as *mut _,
+                weights
+                    .as_ref()
+                    .map_or(std::ptr::null_mut(), |weights| weights.as_ptr() as *mut _),
+                clear as c_int,
+                batch,
+            )
+        };
+        let net = NonNull::new(ptr).ok_or(Error::NullPointer("load_network_custom"))?;
+        Ok(Self { net })
+    }
+
+    /// Input width in pixels.
+    pub fn width(&self) -> usize {
+        unsafe { network_width(self.net.as_ptr()) as usize }
+    }
+
+    /// Input height in pixels.
+    pub fn height(&self) -> usize {
+        unsafe { network_height(self.net.as_ptr()) as usize }
+    }
+
+    /// Number of input channels.
+    pub fn channels(&self) -> usize {
+        self.raw().c as usize
+    }
+
+    /// Batch size the network was loaded with.
+    pub fn batch(&self) -> usize {
+        self.raw().batch as usize
+    }
+
+    /// Number of input values per batch item, i.e. `width * height * channels`.
+    pub fn inputs(&self) -> usize {
+        self.raw().inputs as usize
+    }
+
+    /// Number of output values per batch item.
+    pub fn outputs(&self) -> usize {
+        self.raw().outputs as usize
+    }
+
+    /// The layers of the network.
+    pub fn layers(&self) -> &[layer] {
+        let net = self.raw();
+        if net.layers.is_null() || net.n <= 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(net.layers, net.n as usize) }
+    }
+
+    /// Runs a forward pass with `network_predict_ptr`.
+    ///
+    /// `input` holds `batch() * inputs()` values in darknet's planar layout. The
+    /// returned slice is the output of the last layer, `batch() * outputs()` values.
+    pub fn predict(&mut self, input: &[f32]) -> Result<&[f32]> {
+        let expected = self.batch() * self.inputs();
+        if input.len() != expected {
+            return Err(Error::LengthMismatch {
+                expected,
+                actual: input.len(),
+            });
+        }
+        // darknet only reads from the input buffer.
+        let output = unsafe { network_predict_ptr(self.net.as_ptr(), input.as_ptr() as *mut f32) };
+        if output.is_null() {
+            return Err(Error::NullPointer("network_predict_ptr"));
+        }
+        Ok(unsafe { slice::from_raw_parts(output, self.batch() * self.outputs()) })
+    }
+
+    /// Returns the underlying `network` struct.
+    pub fn raw(&self) -> &network {
+        unsafe { self.net.as_ref() }
+    }
+
+    /// Returns the pointer to the underlying `network`. It stays owned by `self`.
+    pub fn as_ptr(&self) -> *mut network {
+        self.net.as_ptr()
+    }
+}
+
+impl Drop for Network {
+    fn drop(&mut self) {
+        unsafe { free_network_ptr(self.net.as_ptr()) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path::PathError;
+
+    #[test]
+    fn checks_paths_before_loading() {
+        let missing = Path::new("/nonexistent/yolo.cfg");
+        assert_eq!(
+            Network::load(missing, None::<&Path>, false).unwrap_err(),
+            Error::Path(PathError::NotFound(missing.to_owned()))
+        );
+        // Any existing file passes the checks, which darknet is not reached past.
+        let cfg = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let weights = Path::new("/nonexistent/yolo.weights");
+        assert_eq!(
+            Network::load(&cfg, Some(weights), false).unwrap_err(),
+            Error::Path(PathError::NotFound(weights.to_owned()))
+        );
+        assert!(matches!(
+            Network::load_with_batch(&cfg, None::<&Path>, false, 0),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reports_non_utf8_paths_unchanged() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let missing = Path::new(OsStr::from_bytes(b"/nonexistent/yolo\xff.cfg"));
+        assert_eq!(
+            Network::load(missing, None::<&Path>, false).unwrap_err(),
+            Error::Path(PathError::NotFound(missing.to_owned()))
+        );
+    }
+
+    // Needs libdarknet built from source, see `crate::fixtures`.
+    #[cfg(all(
+        unix,
+        not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs"))
+    ))]
+    mod against_c {
+        use super::*;
+        use crate::fixtures::{self, YOLO_CFG};
+        use std::{ffi::OsStr, fs, os::unix::ffi::OsStrExt};
+
+        #[test]
+        fn checks_the_input_length() {
+            let cfg = fixtures::cfg_file("network-predict", YOLO_CFG);
+            let mut net = Network::load_with_batch(&cfg, None::<&Path>, false, 2).unwrap();
+            assert_eq!(
+                (net.width(), net.height(), net.channels(), net.batch()),
+                (32, 32, 3, 2)
+            );
+            assert_eq!(net.inputs(), 32 * 32 * 3);
+            let input = vec![0.5; 2 * net.inputs()];
+            // One batch item is not enough.
+            assert_eq!(
+                net.predict(&input[..net.inputs()]).unwrap_err(),
+                Error::LengthMismatch {
+                    expected: 2 * 32 * 32 * 3,
+                    actual: 32 * 32 * 3
+                }
+            );
+            let outputs = net.outputs();
+            assert_eq!(net.predict(&input).unwrap().len(), 2 * outputs);
+        }
+
+        #[test]
+        fn loads_non_utf8_paths() {
+            let cfg = fixtures::cfg_file("network-non-utf8", YOLO_CFG);
+            let renamed = cfg.with_file_name(OsStr::from_bytes(b"darknet-sys-network-\xff.cfg"));
+            fs::rename(&cfg, &renamed).unwrap();
+            let net = Network::load(&renamed, None::<&Path>, false).unwrap();
+            assert_eq!(net.layers().len(), 3);
+        }
+    }
+}
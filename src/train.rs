@@ -0,0 +1,662 @@
+//! Training detectors with `train_detector` (Unix only).
+//!
+//! `train_detector` blocks until training ends, reports progress only by printing
+//! it, and exits the process on errors. [`TrainConfig::run`] therefore runs it in a
+//! forked child process, as [`crate::guard`] does, turns what it prints into
+//! [`TrainEvent`]s for a callback, and stops the child when training is cancelled.
+//!
+//! ```ignore
+//! use darknet_sys::train::{TrainConfig, TrainEvent};
+//! use std::ops::ControlFlow;
+//!
+//! let outcome = TrainConfig::new("obj.data", "yolov4-tiny-obj.cfg")
+//!     .weights("yolov4-tiny.conv.29")
+//!     .calc_map(true)
+//!     .run(|event| {
+//!         if let TrainEvent::Iteration(it) = event {
+//!             println!("{}: {} avg loss", it.iteration, it.avg_loss);
+//!         }
+//!         ControlFlow::Continue(())
+//!     })?;
+//! ```
+//!
+//! Events can be forwarded to another thread through a channel from the callback,
+//! and a [`CancelToken`] stops training from any thread. The caveats of forking in
+//! [`crate::guard`] apply.
+
+use crate::{
+    guard::{self, FatalError, Termination},
+    path::{existing_path_to_cstring, path_to_cstring, PathError},
+};
+use std::{
+    collections::VecDeque,
+    error, fmt,
+    io::{self, Write},
+    ops::ControlFlow,
+    os::raw::c_int,
+    path::PathBuf,
+    ptr,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+// Lines of output kept for a `FatalError`.
+const OUTPUT_TAIL_LINES: usize = 200;
+// How often a `CancelToken` is checked while darknet prints nothing.
+const POLL_INTERVAL_MS: c_int = 100;
+
+/// One training iteration as printed by darknet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iteration {
+    pub iteration: usize,
+    /// Loss of this iteration.
+    pub loss: f32,
+    /// Running average of the loss.
+    pub avg_loss: f32,
+    pub learning_rate: f32,
+    /// Time the iteration took.
+    pub seconds: f64,
+    /// Number of images trained on so far.
+    pub images: usize,
+    /// darknet's estimate of the remaining time, if it printed one.
+    pub hours_left: Option<f32>,
+}
+
+/// Progress of a training run.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrainEvent {
+    Iteration(Iteration),
+    /// mAP on the validation set, computed with [`TrainConfig::calc_map`] after the
+    /// given iteration.
+    Map {
+        iteration: Option<usize>,
+        iou_thresh: f32,
+        map: f32,
+    },
+    /// darknet saved the weights to this file.
+    WeightsSaved(PathBuf),
+    /// Any other line darknet printed.
+    Output(String),
+}
+
+/// How a training run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrainOutcome {
+    /// `train_detector` returned after `max_batches` iterations.
+    Finished,
+    /// The callback or a [`CancelToken`] stopped training. The last weights file
+    /// may be truncated, see [`CancelToken::cancel`].
+    Cancelled,
+}
+
+/// Cancels a training run, e.g. from another thread. Clones share their state.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the runs using this token. Weights are only kept up to the last file
+    /// darknet saved.
+    ///
+    /// The training process is killed with `SIGTERM`, which darknet does not
+    /// handle, so a weights file it is writing at that moment is left truncated.
+    /// This is most likely `*_last.weights`, which darknet overwrites every 100
+    /// iterations. A file whose [`TrainEvent::WeightsSaved`] was followed by more
+    /// output was written completely.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Errors of [`TrainConfig::run`].
+#[derive(Debug)]
+pub enum TrainError {
+    /// An input file does not exist or its path cannot be passed to darknet.
+    Path(PathError),
+    /// darknet ended the training process, e.g. because of a malformed cfg. The
+    /// output holds the last lines it printed.
+    Fatal(FatalError),
+    /// Creating the pipe, forking or reading the output failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for TrainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrainError::Path(err) => err.fmt(f),
+            TrainError::Fatal(err) => write!(f, "training failed: {}", err),
+            TrainError::Io(err) => write!(f, "cannot run training: {}", err),
+        }
+    }
+}
+
+impl error::Error for TrainError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            TrainError::Path(err) => Some(err),
+            TrainError::Fatal(err) => Some(err),
+            TrainError::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<PathError> for TrainError {
+    fn from(err: PathError) -> Self {
+        TrainError::Path(err)
+    }
+}
+
+impl From<io::Error> for TrainError {
+    fn from(err: io::Error) -> Self {
+        TrainError::Io(err)
+    }
+}
+
+/// Options of a `train_detector` run.
+#[derive(Debug, Clone)]
+pub struct TrainConfig {
+    data: PathBuf,
+    cfg: PathBuf,
+    weights: Option<PathBuf>,
+    gpus: Vec<i32>,
+    clear: bool,
+    calc_map: bool,
+    map_thresh: f32,
+    map_iou_thresh: f32,
+    benchmark_layers: bool,
+    chart_path: Option<PathBuf>,
+    cancel: Option<CancelToken>,
+}
+
+impl TrainConfig {
+    /// Trains the network in the `cfg` file on the dataset described by the `data`
+    /// file, with its `train`, `valid`, `names` and `backup` entries.
+    pub fn new<D, C>(data: D, cfg: C) -> Self
+    where
+        D: Into<PathBuf>,
+        C: Into<PathBuf>,
+    {
+        Self {
+            data: data.into(),
+            cfg: cfg.into(),
+            weights: None,
+            gpus: vec![0],
+            clear: false,
+            calc_map: false,
+            map_thresh: 0.25,
+            map_iou_thresh: 0.5,
+            benchmark_layers: false,
+            chart_path: None,
+            cancel: None,
+        }
+    }
+
+    /// Weights to start from, e.g. pre-trained convolutional layers.
+    pub fn weights<P: Into<PathBuf>>(mut self, weights: P) -> Self {
+        self.weights = Some(weights.into());
+        self
+    }
+
+    /// GPUs to train on, `[0]` by default. An empty list sets `gpu_index` to -1,
+    /// which trains on the CPU with a libdarknet built without CUDA.
+    pub fn gpus<I: IntoIterator<Item = i32>>(mut self, gpus: I) -> Self {
+        self.gpus = gpus.into_iter().collect();
+        self
+    }
+
+    /// Resets the iteration count stored in the weights, like `-clear`.
+    pub fn clear(mut self, clear: bool) -> Self {
+        self.clear = clear;
+        self
+    }
+
+    /// Computes mAP on the validation set during training, like `-map`.
+    pub fn calc_map(mut self, calc_map: bool) -> Self {
+        self.calc_map = calc_map;
+        self
+    }
+
+    /// Confidence threshold for the precision and recall printed with the mAP,
+    /// 0.25 by default.
+    pub fn map_thresh(mut self, thresh: f32) -> Self {
+        self.map_thresh = thresh;
+        self
+    }
+
+    /// IoU threshold of the mAP, 0.5 by default.
+    pub fn map_iou_thresh(mut self, iou_thresh: f32) -> Self {
+        self.map_iou_thresh = iou_thresh;
+        self
+    }
+
+    /// Prints the time each layer takes, like `-benchmark_layers`.
+    pub fn benchmark_layers(mut self, benchmark_layers: bool) -> Self {
+        self.benchmark_layers = benchmark_layers;
+        self
+    }
+
+    /// Where to save the loss chart, which libdarknet only draws with OpenCV.
+    pub fn chart_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.chart_path = Some(path.into());
+        self
+    }
+
+    /// Stops training when `token` is cancelled.
+    pub fn cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Trains in a child process, calling `callback` for each event until training
+    /// ends or the callback returns [`ControlFlow::Break`].
+    pub fn run<F>(&self, mut callback: F) -> Result<TrainOutcome, TrainError>
+    where
+        F: FnMut(&TrainEvent) -> ControlFlow<()>,
+    {
+        let data = existing_path_to_cstring(&self.data)?;
+        let cfg = existing_path_to_cstring(&self.cfg)?;
+        let weights = self
+            .weights
+            .as_deref()
+            .map(existing_path_to_cstring)
+            .transpose()?;
+        let chart_path = self
+            .chart_path
+            .as_deref()
+            .map(path_to_cstring)
+            .transpose()?;
+        let cpu = self.gpus.is_empty();
+        let mut gpus = if cpu { vec![-1] } else { self.gpus.clone() };
+
+        let (output_read, output_write) = guard::pipe()?;
+        // Unflushed stdio buffers would otherwise be written by both processes.
+        unsafe { libc::fflush(ptr::null_mut()) };
+        let _ = io::stdout().flush();
+
+        let pid = unsafe { libc::fork() };
+        if pid == 0 {
+            unsafe {
+                libc::close(output_read);
+                libc::dup2(output_write, libc::STDOUT_FILENO);
+                libc::dup2(output_write, libc::STDERR_FILENO);
+                libc::close(output_write);
+                if cpu {
+                    crate::gpu_index = -1;
+                }
+                // darknet does not modify the path strings, and skips loading weights
+                // and drawing the chart for null pointers.
+                crate::train_detector(
+                    data.as_ptr() as *mut _,
+                    cfg.as_ptr() as *mut _,
+                    weights
+                        .as_ref()
+                        .map_or(ptr::null_mut(), |weights| weights.as_ptr() as *mut _),
+                    gpus.as_mut_ptr(),
+                    gpus.len() as c_int,
+                    self.clear as c_int,
+                    1,
+                    self.calc_map as c_int,
+                    self.map_thresh,
+                    self.map_iou_thresh,
+                    -1,
+                    0,
+                    self.benchmark_layers as c_int,
+                    chart_path
+                        .as_ref()
+                        .map_or(ptr::null_mut(), |path| path.as_ptr() as *mut _),
+                );
+                libc::fflush(ptr::null_mut());
+                libc::_exit(0);
+            }
+        }
+        guard::close(output_write);
+        if pid < 0 {
+            let err = io::Error::last_os_error();
+            guard::close(output_read);
+            return Err(err.into());
+        }
+
+        let mut run = Run {
+            pid,
+            cancelled: false,
+            iteration: None,
+            tail: VecDeque::new(),
+        };
+        let followed = run.follow(output_read, self.cancel.as_ref(), &mut callback);
+        guard::close(output_read);
+        if followed.is_err() && !run.cancelled {
+            run.cancel();
+        }
+        let status = guard::wait(pid)?;
+        followed?;
+        if run.cancelled {
+            return Ok(TrainOutcome::Cancelled);
+        }
+        if libc::WIFEXITED(status) && libc::WEXITSTATUS(status) == 0 {
+            return Ok(TrainOutcome::Finished);
+        }
+        let termination = if libc::WIFEXITED(status) {
+            Termination::Exited(libc::WEXITSTATUS(status))
+        } else {
+            Termination::Signaled(libc::WTERMSIG(status))
+        };
+        Err(TrainError::Fatal(FatalError {
+            termination,
+            output: Vec::from(run.tail).join("\n"),
+        }))
+    }
+}
+
+// State of a running training child while its output is read.
+struct Run {
+    pid: libc::pid_t,
+    cancelled: bool,
+    iteration: Option<usize>,
+    tail: VecDeque<String>,
+}
+
+impl Run {
+    // Reads the child's output until it closes it, passing events to `callback`.
+    fn follow<F>(
+        &mut self,
+        fd: c_int,
+        token: Option<&CancelToken>,
+        callback: &mut F,
+    ) -> io::Result<()>
+    where
+        F: FnMut(&TrainEvent) -> ControlFlow<()>,
+    {
+        let mut pending = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            if !self.cancelled && matches!(token, Some(token) if token.is_cancelled()) {
+                self.cancel();
+            }
+            let mut poll_fd = libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let ready = unsafe { libc::poll(&mut poll_fd, 1, POLL_INTERVAL_MS) };
+            if ready == 0 {
+                continue;
+            }
+            let read = if ready > 0 {
+                unsafe { libc::read(fd, buf.as_mut_ptr() as *mut _, buf.len()) }
+            } else {
+                -1
+            };
+            if read < 0 {
+                let err = io::Error::last_os_error();
+                if err.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(err);
+            }
+            if read == 0 {
+                break;
+            }
+            pending.extend_from_slice(&buf[..read as usize]);
+            // darknet also uses `\r` to redraw progress lines.
+            while let Some(end) = pending.iter().position(|&b| b == b'\n' || b == b'\r') {
+                let line: Vec<u8> = pending.drain(..=end).collect();
+                self.line(&line[..end], callback);
+            }
+        }
+        self.line(&pending, callback);
+        Ok(())
+    }
+
+    fn line<F>(&mut self, line: &[u8], callback: &mut F)
+    where
+        F: FnMut(&TrainEvent) -> ControlFlow<()>,
+    {
+        let line = String::from_utf8_lossy(line);
+        let line = line.trim();
+        if line.is_empty() {
+            return;
+        }
+        if self.tail.len() == OUTPUT_TAIL_LINES {
+            self.tail.pop_front();
+        }
+        self.tail.push_back(line.to_owned());
+        let event = parse_line(line, self.iteration);
+        if let TrainEvent::Iteration(iteration) = &event {
+            self.iteration = Some(iteration.iteration);
+        }
+        if !self.cancelled && callback(&event).is_break() {
+            self.cancel();
+        }
+    }
+
+    // darknet does not handle SIGTERM, so this ends the child right away, even in
+    // the middle of saving weights.
+    fn cancel(&mut self) {
+        self.cancelled = true;
+        unsafe { libc::kill(self.pid, libc::SIGTERM) };
+    }
+}
+
+fn parse_line(line: &str, iteration: Option<usize>) -> TrainEvent {
+    if let Some(iteration) = parse_iteration(line) {
+        return TrainEvent::Iteration(iteration);
+    }
+    if let Some((iou_thresh, map)) = parse_map(line) {
+        return TrainEvent::Map {
+            iteration,
+            iou_thresh,
+            map,
+        };
+    }
+    match line.strip_prefix("Saving weights to ") {
+        Some(path) => TrainEvent::WeightsSaved(PathBuf::from(path)),
+        None => TrainEvent::Output(line.to_owned()),
+    }
+}
+
+// `1: 1045.2, 1045.2 avg loss, 0.000000 rate, 2.5 seconds, 64 images, 12.3 hours left`,
+// where older versions print `avg` instead of `avg loss` and no time left.
+fn parse_iteration(line: &str) -> Option<Iteration> {
+    let (iteration, rest) = line.split_once(": ")?;
+    let mut fields = rest.split(", ");
+    Some(Iteration {
+        iteration: iteration.parse().ok()?,
+        loss: fields.next()?.parse().ok()?,
+        avg_loss: field(fields.next()?, &[" avg loss", " avg"])?,
+        learning_rate: field(fields.next()?, &[" rate"])?,
+        seconds: field(fields.next()?, &[" seconds"])?,
+        images: field(fields.next()?, &[" images"])?,
+        hours_left: fields
+            .next()
+            .and_then(|hours| field(hours, &[" hours left"])),
+    })
+}
+
+fn field<T: FromStr>(field: &str, suffixes: &[&str]) -> Option<T> {
+    suffixes
+        .iter()
+        .find_map(|suffix| field.strip_suffix(suffix))
+        .and_then(|value| value.trim().parse().ok())
+}
+
+// `mean_average_precision (mAP@0.50) = 0.634521`, or from older versions
+// `mean average precision (mAP@0.50) = 0.634521, or 63.45 %`.
+fn parse_map(line: &str) -> Option<(f32, f32)> {
+    let rest = line
+        .strip_prefix("mean_average_precision (mAP@")
+        .or_else(|| line.strip_prefix("mean average precision (mAP@"))?;
+    let (iou_thresh, rest) = rest.split_once(") = ")?;
+    let map = rest.split(|c: char| c == ',' || c.is_whitespace()).next()?;
+    Some((iou_thresh.parse().ok()?, map.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_iterations() {
+        let event = parse_line(
+            "12: 845.5, 901.25 avg loss, 0.000001 rate, 0.432000 seconds, 768 images, 2.250000 hours left",
+            None,
+        );
+        assert_eq!(
+            event,
+            TrainEvent::Iteration(Iteration {
+                iteration: 12,
+                loss: 845.5,
+                avg_loss: 901.25,
+                learning_rate: 0.000001,
+                seconds: 0.432,
+                images: 768,
+                hours_left: Some(2.25),
+            })
+        );
+        match parse_line(
+            "3: 12.5, 13.0 avg, 0.001000 rate, 1.0 seconds, 24 images",
+            None,
+        ) {
+            TrainEvent::Iteration(iteration) => {
+                assert_eq!((iteration.avg_loss, iteration.hours_left), (13.0, None))
+            }
+            other => panic!("expected an iteration, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_map_and_saved_weights() {
+        assert_eq!(
+            parse_line("mean_average_precision (mAP@0.50) = 0.634521", Some(1000)),
+            TrainEvent::Map {
+                iteration: Some(1000),
+                iou_thresh: 0.5,
+                map: 0.634521
+            }
+        );
+        assert_eq!(
+            parse_line(
+                "mean average precision (mAP@0.75) = 0.250000, or 25.00 %",
+                None
+            ),
+            TrainEvent::Map {
+                iteration: None,
+                iou_thresh: 0.75,
+                map: 0.25
+            }
+        );
+        assert_eq!(
+            parse_line("Saving weights to backup/tiny_final.weights", None),
+            TrainEvent::WeightsSaved("backup/tiny_final.weights".into())
+        );
+        assert_eq!(
+            parse_line("Loaded: 0.000021 seconds", None),
+            TrainEvent::Output("Loaded: 0.000021 seconds".to_owned())
+        );
+    }
+
+    // Needs libdarknet built from source, see `crate::fixtures`.
+    #[cfg(not(any(feature = "runtime", feature = "dlopen", feature = "docs-rs")))]
+    mod against_c {
+        use super::*;
+        use crate::fixtures::{self, square};
+        use std::fs;
+
+        const CFG: &str = "[net]
+batch=2
+subdivisions=1
+width=32
+height=32
+channels=3
+learning_rate=0.001
+burn_in=0
+max_batches=MAX_BATCHES
+policy=steps
+steps=1000000
+scales=.1
+
+[convolutional]
+batch_normalize=1
+filters=8
+size=3
+stride=2
+pad=1
+activation=leaky
+
+[convolutional]
+filters=18
+size=1
+stride=1
+pad=1
+activation=linear
+
+[yolo]
+mask=0,1,2
+anchors=4,4, 8,8, 16,16
+classes=1
+num=3
+";
+
+        fn dataset(name: &str, max_batches: usize) -> TrainConfig {
+            let cfg = CFG.replace("MAX_BATCHES", &max_batches.to_string());
+            let dataset = fixtures::dataset(
+                &format!("train-{}", name),
+                &cfg,
+                &["square"],
+                &[(4, vec![square(0, 4)]), (12, vec![square(0, 12)])],
+            );
+            TrainConfig::new(dataset.data, dataset.cfg).gpus(Vec::new())
+        }
+
+        #[test]
+        fn trains_on_cpu() {
+            let mut events = Vec::new();
+            let outcome = dataset("finish", 2)
+                .run(|event| {
+                    events.push(event.clone());
+                    ControlFlow::Continue(())
+                })
+                .unwrap();
+            assert_eq!(outcome, TrainOutcome::Finished);
+            assert!(events
+                .iter()
+                .any(|event| matches!(event, TrainEvent::Iteration(it) if it.iteration == 2)));
+            assert!(events.iter().any(|event| matches!(
+                event,
+                TrainEvent::WeightsSaved(path) if path.exists()
+            )));
+        }
+
+        #[test]
+        fn cancels_from_the_callback() {
+            let outcome = dataset("cancel", 100_000)
+                .run(|event| match event {
+                    TrainEvent::Iteration(_) => ControlFlow::Break(()),
+                    _ => ControlFlow::Continue(()),
+                })
+                .unwrap();
+            assert_eq!(outcome, TrainOutcome::Cancelled);
+        }
+
+        #[test]
+        fn reports_malformed_cfg() {
+            let config = dataset("malformed", 2);
+            fs::write(&config.cfg, "[convolutional]\nfilters=1\n").unwrap();
+            match config.run(|_| ControlFlow::Continue(())) {
+                Err(TrainError::Fatal(err)) => {
+                    assert!(err.message().contains("First section must be [net]"))
+                }
+                other => panic!("expected a fatal error, got {:?}", other),
+            }
+        }
+    }
+}
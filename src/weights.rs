@@ -0,0 +1,720 @@
+//! Reader and writer for darknet `.weights` files.
+//!
+//! A weights file starts with three `i32`s, the format `major`, `minor` and
+//! `revision`, followed by the number of images seen during training: a `u64` for
+//! versions 0.2 and later, a `u32` before. The parameters of each layer with weights
+//! follow in network order, as little-endian `f32`s:
+//!
+//! - convolutional: biases, then scales, rolling mean and rolling variance if
+//!   `batch_normalize=1`, then weights
+//! - connected: biases, weights, then the batch norm blobs if `batch_normalize=1`
+//! - batchnorm: scales, rolling mean, rolling variance
+//! - shortcut with `weights_type` other than `none`, and implicit: weights
+//!
+//! Blob sizes depend on the shapes flowing through the network, so reading needs a
+//! parsed [`NetworkCfg`]. As in darknet's `load_weights_upto()`, files may end after
+//! any layer, for example pre-trained backbones such as `yolov4.conv.137`.
+
+use crate::{
+    cfg::{CfgError, LayerSection, NetworkCfg},
+    LAYER_TYPE,
+};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt, fs,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+/// Errors produced while reading a weights file.
+#[derive(Debug)]
+pub enum WeightsError {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// The cfg is missing or has invalid values needed to size the blobs.
+    Cfg(CfgError),
+    /// The layer's shape or weights cannot be determined by this module.
+    UnsupportedLayer {
+        index: usize,
+        layer_type: LAYER_TYPE,
+    },
+    /// A route, shortcut or similar layer refers to a layer that does not exist.
+    InvalidLayerReference { index: usize, reference: i64 },
+    /// The file ends in the middle of a layer.
+    Truncated { index: usize },
+}
+
+impl fmt::Display for WeightsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightsError::Io(err) => write!(f, "{}", err),
+            WeightsError::Cfg(err) => write!(f, "{}", err),
+            WeightsError::UnsupportedLayer { index, layer_type } => write!(
+                f,
+                "layer {}: [{}] layers are not supported",
+                index, layer_type
+            ),
+            WeightsError::InvalidLayerReference { index, reference } => write!(
+                f,
+                "layer {}: reference to layer {} is out of range",
+                index, reference
+            ),
+            WeightsError::Truncated { index } => {
+                write!(f, "weights file ends inside layer {}", index)
+            }
+        }
+    }
+}
+
+impl Error for WeightsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WeightsError::Io(err) => Some(err),
+            WeightsError::Cfg(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for WeightsError {
+    fn from(err: io::Error) -> Self {
+        WeightsError::Io(err)
+    }
+}
+
+impl From<CfgError> for WeightsError {
+    fn from(err: CfgError) -> Self {
+        WeightsError::Cfg(err)
+    }
+}
+
+/// The header of a weights file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeightsHeader {
+    pub major: i32,
+    pub minor: i32,
+    pub revision: i32,
+    /// Number of images seen during training.
+    pub seen: u64,
+}
+
+impl WeightsHeader {
+    /// Whether `seen` is stored as 64 bits, which is the case from version 0.2 on.
+    pub fn has_64bit_seen(&self) -> bool {
+        // darknet's `major * 10 + minor >= 2`, without overflowing.
+        i64::from(self.major) * 10 + i64::from(self.minor) >= 2
+    }
+
+    /// Whether connected layer weights are stored transposed. Files written by
+    /// darknet itself never are.
+    pub fn is_transposed(&self) -> bool {
+        self.major > 1000 || self.minor > 1000
+    }
+}
+
+impl Default for WeightsHeader {
+    /// The header darknet's `save_weights()` writes, version 0.2.5.
+    fn default() -> Self {
+        Self {
+            major: 0,
+            minor: 2,
+            revision: 5,
+            seen: 0,
+        }
+    }
+}
+
+/// The parameters of one layer. Blobs a layer does not have are empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerWeights {
+    /// Index of the layer in the cfg, not counting `[net]`.
+    pub index: usize,
+    pub layer_type: LAYER_TYPE,
+    pub biases: Vec<f32>,
+    pub scales: Vec<f32>,
+    pub rolling_mean: Vec<f32>,
+    pub rolling_variance: Vec<f32>,
+    pub weights: Vec<f32>,
+}
+
+impl LayerWeights {
+    /// Total number of values in all blobs.
+    pub fn len(&self) -> usize {
+        self.biases.len()
+            + self.scales.len()
+            + self.rolling_mean.len()
+            + self.rolling_variance.len()
+            + self.weights.len()
+    }
+
+    /// Returns `true` if the layer has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn batch_norm(&mut self, n: usize) {
+        self.scales = vec![0.0; n];
+        self.rolling_mean = vec![0.0; n];
+        self.rolling_variance = vec![0.0; n];
+    }
+
+    // Blobs in the order they are stored in the file.
+    fn blobs(&self) -> Vec<&[f32]> {
+        let bn = [
+            &self.scales[..],
+            &self.rolling_mean[..],
+            &self.rolling_variance[..],
+        ];
+        match self.layer_type {
+            LAYER_TYPE::CONNECTED => [&[&self.biases[..], &self.weights[..]][..], &bn[..]].concat(),
+            LAYER_TYPE::BATCHNORM => bn.to_vec(),
+            _ => [&[&self.biases[..]][..], &bn[..], &[&self.weights[..]][..]].concat(),
+        }
+    }
+
+    fn blobs_mut(&mut self) -> Vec<&mut Vec<f32>> {
+        let LayerWeights {
+            layer_type,
+            biases,
+            scales,
+            rolling_mean,
+            rolling_variance,
+            weights,
+            ..
+        } = self;
+        match layer_type {
+            LAYER_TYPE::CONNECTED => vec![biases, weights, scales, rolling_mean, rolling_variance],
+            LAYER_TYPE::BATCHNORM => vec![scales, rolling_mean, rolling_variance],
+            _ => vec![biases, scales, rolling_mean, rolling_variance, weights],
+        }
+    }
+}
+
+/// The contents of a weights file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weights {
+    pub header: WeightsHeader,
+    /// Layers with parameters, in network order. Layers without parameters, those
+    /// with `dontload=1`, and those after the end of a partial file are absent.
+    pub layers: Vec<LayerWeights>,
+}
+
+impl Weights {
+    /// Reads weights for the network described by `cfg`.
+    pub fn read<R: Read>(reader: R, cfg: &NetworkCfg) -> Result<Self, WeightsError> {
+        let mut reader = reader;
+        let major = read_i32(&mut reader)?;
+        let minor = read_i32(&mut reader)?;
+        let revision = read_i32(&mut reader)?;
+        let mut header = WeightsHeader {
+            major,
+            minor,
+            revision,
+            seen: 0,
+        };
+        header.seen = if header.has_64bit_seen() {
+            let mut buf = [0; 8];
+            reader.read_exact(&mut buf)?;
+            u64::from_le_bytes(buf)
+        } else {
+            u64::from(read_i32(&mut reader)? as u32)
+        };
+
+        let mut layers = Vec::new();
+        for mut layer in empty_layers(cfg)? {
+            let mut at_start = true;
+            for blob in layer.blobs_mut() {
+                match read_f32s(&mut reader, blob) {
+                    Ok(()) => at_start &= blob.is_empty(),
+                    // End of a partial file, between two layers.
+                    Err(ReadError::Eof { read: 0 }) if at_start => {
+                        return Ok(Self { header, layers })
+                    }
+                    Err(ReadError::Eof { .. }) => {
+                        return Err(WeightsError::Truncated { index: layer.index })
+                    }
+                    Err(ReadError::Io(err)) => return Err(err.into()),
+                }
+            }
+            layers.push(layer);
+        }
+        Ok(Self { header, layers })
+    }
+
+    /// Reads the weights file at `path` for the network described by `cfg`.
+    pub fn from_file<P: AsRef<Path>>(path: P, cfg: &NetworkCfg) -> Result<Self, WeightsError> {
+        Self::read(BufReader::new(fs::File::open(path)?), cfg)
+    }
+
+    /// Writes the weights in darknet's format.
+    ///
+    /// Blobs are written as they are, so their sizes must match the cfg the file will
+    /// be loaded with. Fails with [`io::ErrorKind::InvalidInput`], before writing
+    /// anything, if the header's version stores `seen` as 32 bits and it does not
+    /// fit.
+    pub fn write<W: Write>(&self, writer: W) -> io::Result<()> {
+        let seen = if self.header.has_64bit_seen() {
+            self.header.seen.to_le_bytes().to_vec()
+        } else {
+            u32::try_from(self.header.seen)
+                .map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "seen = {} does not fit in the 32 bits of version {}.{}",
+                            self.header.seen, self.header.major, self.header.minor
+                        ),
+                    )
+                })?
+                .to_le_bytes()
+                .to_vec()
+        };
+        let mut writer = writer;
+        writer.write_all(&self.header.major.to_le_bytes())?;
+        writer.write_all(&self.header.minor.to_le_bytes())?;
+        writer.write_all(&self.header.revision.to_le_bytes())?;
+        writer.write_all(&seen)?;
+        for layer in &self.layers {
+            for blob in layer.blobs() {
+                for value in blob {
+                    writer.write_all(&value.to_le_bytes())?;
+                }
+            }
+        }
+        writer.flush()
+    }
+
+    /// Writes the weights to the file at `path`.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write(BufWriter::new(fs::File::create(path)?))
+    }
+
+    /// Returns the weights of the layer with cfg index `index`.
+    pub fn layer(&self, index: usize) -> Option<&LayerWeights> {
+        self.layers.iter().find(|layer| layer.index == index)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Shape {
+    w: usize,
+    h: usize,
+    c: usize,
+}
+
+// Allocates zeroed blobs with the sizes darknet reads for each layer with weights,
+// propagating shapes like `parse_network_cfg_custom()`.
+fn empty_layers(cfg: &NetworkCfg) -> Result<Vec<LayerWeights>, WeightsError> {
+    let mut input = Shape {
+        w: cfg.net.width()?,
+        h: cfg.net.height()?,
+        c: cfg.net.channels()?,
+    };
+    let mut outputs: Vec<Shape> = Vec::with_capacity(cfg.layers.len());
+    let mut layers = Vec::new();
+    for (index, layer) in cfg.layers.iter().enumerate() {
+        let s = &layer.section;
+        let mut weights = LayerWeights {
+            index,
+            layer_type: layer.layer_type,
+            biases: Vec::new(),
+            scales: Vec::new(),
+            rolling_mean: Vec::new(),
+            rolling_variance: Vec::new(),
+            weights: Vec::new(),
+        };
+        let batch_normalize = s.get_or("batch_normalize", 0)? != 0;
+        let output = match layer.layer_type {
+            LAYER_TYPE::CONVOLUTIONAL => {
+                let filters: usize = s.get_or("filters", 1)?;
+                let size: usize = s.get_or("size", 1)?;
+                let stride: usize = s.get_or("stride", 1)?;
+                let stride_x = positive_or(s.get("stride_x")?, stride);
+                let stride_y = positive_or(s.get("stride_y")?, stride);
+                let dilation: usize = s.get_or("dilation", 1)?;
+                let groups: usize = s.get_or("groups", 1)?;
+                let padding = if s.get_or("pad", 0)? != 0 {
+                    size / 2
+                } else {
+                    s.get_or("padding", 0)?
+                };
+                let kernel = (size - 1) * dilation + 1;
+                if s.get::<i64>("share_index")?.is_none() {
+                    weights.biases = vec![0.0; filters];
+                    if batch_normalize {
+                        weights.batch_norm(filters);
+                    }
+                    weights.weights = vec![0.0; input.c / groups.max(1) * filters * size * size];
+                }
+                Shape {
+                    w: (input.w + 2 * padding).saturating_sub(kernel) / stride_x.max(1) + 1,
+                    h: (input.h + 2 * padding).saturating_sub(kernel) / stride_y.max(1) + 1,
+                    c: filters,
+                }
+            }
+            LAYER_TYPE::CONNECTED => {
+                let outputs: usize = s.get_or("output", 1)?;
+                weights.biases = vec![0.0; outputs];
+                weights.weights = vec![0.0; outputs * input.w * input.h * input.c];
+                if batch_normalize {
+                    weights.batch_norm(outputs);
+                }
+                Shape {
+                    w: 1,
+                    h: 1,
+                    c: outputs,
+                }
+            }
+            LAYER_TYPE::BATCHNORM => {
+                weights.batch_norm(input.c);
+                input
+            }
+            LAYER_TYPE::SHORTCUT => {
+                let from = references(layer, index, "from", &outputs)?;
+                weights.weights = match s.get_str("weights_type").unwrap_or("none") {
+                    "per_feature" => vec![0.0; from.len() + 1],
+                    "per_channel" => vec![0.0; (from.len() + 1) * input.c],
+                    _ => Vec::new(),
+                };
+                input
+            }
+            LAYER_TYPE::IMPLICIT => {
+                let filters: usize = s.get_or("filters", 128)?;
+                weights.weights = vec![0.0; filters];
+                Shape {
+                    w: 1,
+                    h: 1,
+                    c: filters,
+                }
+            }
+            LAYER_TYPE::MAXPOOL | LAYER_TYPE::LOCAL_AVGPOOL => {
+                let stride: usize = s.get_or("stride", 1)?;
+                let stride_x = positive_or(s.get("stride_x")?, stride);
+                let stride_y = positive_or(s.get("stride_y")?, stride);
+                let size: usize = s.get_or("size", stride)?;
+                let padding: usize = s.get_or("padding", size.saturating_sub(1))?;
+                let c = if s.get_or("maxpool_depth", 0)? != 0 {
+                    s.get_or("out_channels", 1)?
+                } else {
+                    input.c
+                };
+                Shape {
+                    w: (input.w + padding).saturating_sub(size) / stride_x.max(1) + 1,
+                    h: (input.h + padding).saturating_sub(size) / stride_y.max(1) + 1,
+                    c,
+                }
+            }
+            LAYER_TYPE::AVGPOOL => Shape {
+                w: 1,
+                h: 1,
+                c: input.c,
+            },
+            LAYER_TYPE::ROUTE => {
+                let sources = references(layer, index, "layers", &outputs)?;
+                let groups: usize = s.get_or("groups", 1)?;
+                let first = sources[0];
+                Shape {
+                    w: first.w,
+                    h: first.h,
+                    c: sources.iter().map(|shape| shape.c).sum::<usize>() / groups.max(1),
+                }
+            }
+            LAYER_TYPE::UPSAMPLE => {
+                let stride: i64 = s.get_or("stride", 2)?;
+                let stride_abs = stride.unsigned_abs() as usize;
+                if stride < 0 {
+                    Shape {
+                        w: input.w / stride_abs.max(1),
+                        h: input.h / stride_abs.max(1),
+                        c: input.c,
+                    }
+                } else {
+                    Shape {
+                        w: input.w * stride_abs,
+                        h: input.h * stride_abs,
+                        c: input.c,
+                    }
+                }
+            }
+            LAYER_TYPE::REORG | LAYER_TYPE::REORG_OLD => {
+                let stride: usize = s.get_or::<usize>("stride", 1)?.max(1);
+                if s.get_or("reverse", 0)? != 0 {
+                    Shape {
+                        w: input.w * stride,
+                        h: input.h * stride,
+                        c: input.c / (stride * stride),
+                    }
+                } else {
+                    Shape {
+                        w: input.w / stride,
+                        h: input.h / stride,
+                        c: input.c * stride * stride,
+                    }
+                }
+            }
+            LAYER_TYPE::SCALE_CHANNELS => references(layer, index, "from", &outputs)?[0],
+            LAYER_TYPE::YOLO
+            | LAYER_TYPE::GAUSSIAN_YOLO
+            | LAYER_TYPE::REGION
+            | LAYER_TYPE::DETECTION
+            | LAYER_TYPE::DROPOUT
+            | LAYER_TYPE::ACTIVE
+            | LAYER_TYPE::SOFTMAX
+            | LAYER_TYPE::COST
+            | LAYER_TYPE::SAM
+            | LAYER_TYPE::EMPTY
+            | LAYER_TYPE::LOGXENT
+            | LAYER_TYPE::L2NORM
+            | LAYER_TYPE::CONTRASTIVE => input,
+            layer_type => return Err(WeightsError::UnsupportedLayer { index, layer_type }),
+        };
+        let dontload = s.get_or("dontload", 0)? != 0;
+        // Only convolutional and connected layers honor `dontloadscales`;
+        // `load_batchnorm_weights()` always reads them.
+        let honors_dontloadscales = matches!(
+            layer.layer_type,
+            LAYER_TYPE::CONVOLUTIONAL | LAYER_TYPE::CONNECTED
+        );
+        if honors_dontloadscales && s.get_or("dontloadscales", 0)? != 0 {
+            weights.scales.clear();
+            weights.rolling_mean.clear();
+            weights.rolling_variance.clear();
+        }
+        if !dontload && !weights.is_empty() {
+            layers.push(weights);
+        }
+        outputs.push(output);
+        input = output;
+    }
+    Ok(layers)
+}
+
+fn positive_or(value: Option<i64>, default: usize) -> usize {
+    match value {
+        Some(value) if value > 0 => value as usize,
+        _ => default,
+    }
+}
+
+// Shapes of the layers listed in `key`. Negative entries are relative to `index`.
+fn references(
+    layer: &LayerSection,
+    index: usize,
+    key: &str,
+    outputs: &[Shape],
+) -> Result<Vec<Shape>, WeightsError> {
+    let list: Vec<i64> = layer.section.get_list(key)?.unwrap_or_default();
+    if list.is_empty() {
+        return Err(CfgError::InvalidValue {
+            line: layer.section.line,
+            key: key.to_owned(),
+            value: String::new(),
+        }
+        .into());
+    }
+    list.into_iter()
+        .map(|reference| {
+            let absolute = if reference < 0 {
+                index as i64 + reference
+            } else {
+                reference
+            };
+            usize::try_from(absolute)
+                .ok()
+                .and_then(|i| outputs.get(i).copied())
+                .ok_or(WeightsError::InvalidLayerReference { index, reference })
+        })
+        .collect()
+}
+
+fn read_i32<R: Read>(reader: &mut R) -> io::Result<i32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(i32::from_le_bytes(buf))
+}
+
+enum ReadError {
+    Eof { read: usize },
+    Io(io::Error),
+}
+
+// Fills `blob` from `reader`, reporting how many bytes were read before EOF.
+fn read_f32s<R: Read>(reader: &mut R, blob: &mut [f32]) -> Result<(), ReadError> {
+    let mut bytes = vec![0; blob.len() * 4];
+    let mut read = 0;
+    while read < bytes.len() {
+        match reader.read(&mut bytes[read..]) {
+            Ok(0) => return Err(ReadError::Eof { read }),
+            Ok(n) => read += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(ReadError::Io(err)),
+        }
+    }
+    for (value, chunk) in blob.iter_mut().zip(bytes.chunks_exact(4)) {
+        *value = f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CFG: &str = "\
+[net]
+width=8
+height=8
+channels=3
+
+[convolutional]
+batch_normalize=1
+filters=4
+size=3
+stride=1
+pad=1
+activation=leaky
+
+[maxpool]
+size=2
+stride=2
+
+[convolutional]
+filters=2
+size=1
+stride=1
+activation=linear
+
+[route]
+layers=-1,-3
+
+[shortcut]
+from=-2
+weights_type=per_channel
+";
+
+    #[test]
+    fn sizes_blobs_from_cfg() {
+        let cfg: NetworkCfg = CFG.parse().unwrap();
+        let layers = empty_layers(&cfg).unwrap();
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[0].biases.len(), 4);
+        assert_eq!(layers[0].scales.len(), 4);
+        assert_eq!(layers[0].weights.len(), 3 * 4 * 3 * 3);
+        assert_eq!(layers[1].index, 2);
+        assert_eq!(layers[1].weights.len(), 4 * 2);
+        assert!(layers[1].scales.is_empty());
+        // The route concatenates 2 + 4 channels.
+        assert_eq!(layers[2].weights.len(), 2 * 6);
+    }
+
+    #[test]
+    fn dontloadscales_only_applies_to_convolutional_and_connected() {
+        let cfg: NetworkCfg = "[net]
+width=4
+height=4
+channels=2
+
+[convolutional]
+batch_normalize=1
+filters=3
+size=1
+dontloadscales=1
+
+[batchnorm]
+dontloadscales=1
+
+[logistic]
+
+[l2norm]
+
+[connected]
+output=2
+batch_normalize=1
+dontloadscales=1
+"
+        .parse()
+        .unwrap();
+        let layers = empty_layers(&cfg).unwrap();
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[0].biases.len(), 3);
+        assert!(layers[0].scales.is_empty() && layers[0].rolling_mean.is_empty());
+        assert_eq!(layers[1].layer_type, LAYER_TYPE::BATCHNORM);
+        assert_eq!(layers[1].scales.len(), 3);
+        assert_eq!(layers[1].rolling_variance.len(), 3);
+        // [logistic] and [l2norm] keep the 4x4x3 shape.
+        assert_eq!(layers[2].index, 4);
+        assert_eq!(layers[2].weights.len(), 2 * 4 * 4 * 3);
+        assert!(layers[2].scales.is_empty());
+    }
+
+    #[test]
+    fn round_trips_and_stops_at_layer_boundaries() {
+        let cfg: NetworkCfg = CFG.parse().unwrap();
+        let mut layers = empty_layers(&cfg).unwrap();
+        let mut next = 0.0;
+        for layer in &mut layers {
+            for blob in layer.blobs_mut() {
+                for value in blob.iter_mut() {
+                    *value = next;
+                    next += 1.0;
+                }
+            }
+        }
+        let weights = Weights {
+            header: WeightsHeader {
+                seen: 1 << 40,
+                ..WeightsHeader::default()
+            },
+            layers,
+        };
+        let mut bytes = Vec::new();
+        weights.write(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 20 + 4 * next as usize);
+        assert_eq!(Weights::read(&bytes[..], &cfg).unwrap(), weights);
+
+        let first_layer_end = 20 + 4 * weights.layers[0].len();
+        let partial = Weights::read(&bytes[..first_layer_end], &cfg).unwrap();
+        assert_eq!(partial.layers, weights.layers[..1]);
+
+        let err = Weights::read(&bytes[..first_layer_end + 4], &cfg).unwrap_err();
+        assert!(matches!(err, WeightsError::Truncated { index: 2 }));
+    }
+
+    #[test]
+    fn picks_the_seen_size_by_version() {
+        let header = |major, minor| WeightsHeader {
+            major,
+            minor,
+            ..WeightsHeader::default()
+        };
+        assert!(header(0, 2).has_64bit_seen());
+        assert!(header(1, 0).has_64bit_seen());
+        assert!(!header(0, 1).has_64bit_seen());
+        // Large versions of transposed files do not overflow.
+        assert!(header(i32::MAX, i32::MAX).has_64bit_seen());
+        assert!(!header(i32::MIN, 0).has_64bit_seen());
+    }
+
+    #[test]
+    fn refuses_to_truncate_seen() {
+        let mut weights = Weights {
+            header: WeightsHeader {
+                minor: 1,
+                seen: u64::from(u32::MAX),
+                ..WeightsHeader::default()
+            },
+            layers: Vec::new(),
+        };
+        let mut bytes = Vec::new();
+        weights.write(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[12..], u32::MAX.to_le_bytes());
+
+        weights.header.seen += 1;
+        let mut bytes = Vec::new();
+        let err = weights.write(&mut bytes).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(bytes.is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! Development tasks for darknet-sys.
+//!
+//! ```sh
+//! cargo run --manifest-path xtask/Cargo.toml -- bindings [--check] [--include DIR]
+//! ```
+//!
+//! `bindings` regenerates `src/bindings/` for every target in
+//! `PREGENERATED_TARGETS` from the headers in `darknet/include`, or `DIR`. With
+//! `--check` nothing is written, and the task fails if a checked-in file differs
+//! from what bindgen generates.
+//!
+//! clang needs each target's C headers. For targets whose headers are not installed
+//! system-wide, set `SYSROOT_<target>` with `-` replaced by `_`, e.g.
+//! `SYSROOT_aarch64_unknown_linux_gnu`.
+
+// Shared with build.rs, which also selects bindings by target.
+#[allow(dead_code)]
+#[path = "../../codegen/bindings.rs"]
+mod codegen;
+
+use anyhow::{bail, format_err, Result};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        Some("bindings") => {}
+        _ => bail!("usage: xtask bindings [--check] [--include DIR]"),
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask is in a subdirectory")
+        .to_owned();
+    let mut check = false;
+    let mut include = root.join("darknet").join("include");
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--include" => {
+                include = args
+                    .next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| format_err!("--include needs a directory"))?
+            }
+            other => bail!("unknown argument {}", other),
+        }
+    }
+    let header = include.join("darknet.h");
+    if !header.is_file() {
+        bail!(
+            "{} does not exist; run `git submodule update --init` or pass --include",
+            header.display()
+        );
+    }
+
+    let mut drifted = Vec::new();
+    for &(target, stem) in codegen::PREGENERATED_TARGETS {
+        for &dlopen in &[false, true] {
+            let path = root
+                .join("src")
+                .join("bindings")
+                .join(codegen::file_name(stem, dlopen));
+            let generated = generate(&header, target, dlopen)?;
+            if check {
+                if fs::read_to_string(&path).ok().as_deref() != Some(&generated) {
+                    drifted.push(path);
+                }
+            } else {
+                fs::write(&path, generated)?;
+                println!("wrote {}", path.display());
+            }
+        }
+    }
+    if !drifted.is_empty() {
+        for path in &drifted {
+            eprintln!("out of date: {}", path.display());
+        }
+        bail!(
+            "{} bindings differ from the headers; run `cargo run --manifest-path xtask/Cargo.toml -- bindings`",
+            drifted.len()
+        );
+    }
+    Ok(())
+}
+
+fn generate(header: &Path, target: &str, dlopen: bool) -> Result<String> {
+    let mut builder = codegen::builder(header, dlopen).clang_arg(format!("--target={}", target));
+    if let Some(sysroot) = env::var_os(format!("SYSROOT_{}", target.replace('-', "_"))) {
+        builder = builder.clang_arg(format!("--sysroot={}", Path::new(&sysroot).display()));
+    }
+    let bindings = builder
+        .generate()
+        .map_err(|_| format_err!("failed to generate bindings for {}", target))?
+        .to_string();
+    Ok(if dlopen {
+        bindings.clone() + &codegen::symbol_list(&bindings)
+    } else {
+        bindings
+    })
+}